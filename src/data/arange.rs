@@ -31,5 +31,23 @@ pub trait Arange<E: Dtype>: DeviceStorage + ZerosTensor<E> + TensorFromVec<E> {
         }
         self.tensor_from_vec(data, (n,))
     }
+
+    /// Generates a tensor of any shape with ordered data from 0 to the number of
+    /// elements, in row major order.
+    ///
+    /// ```rust
+    /// # use dfdx::{prelude::*, data::Arange};
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.arange_like(&(Const, Const));
+    /// assert_eq!(t.array(), [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    /// ```
+    fn arange_like<S: HasShape>(&self, src: &S) -> Tensor<S::Shape, E, Self> {
+        let shape = *src.shape();
+        let mut data = Vec::with_capacity(shape.num_elements());
+        for i in 0..shape.num_elements() {
+            data.push(E::from_usize(i).unwrap());
+        }
+        self.tensor_from_vec(data, shape)
+    }
 }
 impl<E: Dtype, D: ZerosTensor<E> + TensorFromVec<E>> Arange<E> for D {}
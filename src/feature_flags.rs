@@ -68,6 +68,19 @@
 //! Used to enable the threading feature of `matrixmultiply`. This makes matmuls
 //! substantially faster!
 //!
+//! # "rayon"
+//!
+//! Uses [rayon](https://crates.io/crates/rayon) to run Cpu elementwise kernels across
+//! multiple threads. Also enables "cpu-par-matmul".
+//!
+//! Backward passes of broadcasted binary ops are still sequential, so gradient
+//! accumulation happens in the same order as without this feature.
+//!
+//! Example:
+//! ```toml
+//! dfdx = { version = "...", features = ["rayon"] }
+//! ```
+//!
 //! # "cpu-mkl-matmul"
 //!
 //! Enables using the `Intel MKL` libraries (assuming you installed it already) for matrix multiplication.
@@ -92,6 +105,17 @@
 //!
 //! `build.rs` will fail helpfully if you don't have the correct path/environment variables.
 //!
+//! # "timings"
+//!
+//! Records cumulative time spent in each kernel, which can be retrieved with
+//! `Cpu::take_timings()` or `Cuda::take_timings()`. The Cpu measures wall-clock
+//! time, and Cuda records CUDA events around each kernel launch.
+//!
+//! Example:
+//! ```toml
+//! dfdx = { version = "...", features = ["timings"] }
+//! ```
+//!
 //! # "numpy"
 //!
 //! Enables saving and loading arrays to .npy files, and saving and loading nn to .npz files.
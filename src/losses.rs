@@ -86,6 +86,45 @@ pub fn cross_entropy_with_logits_loss<S: Shape, E: Dtype, D: Device<E>, T: Tape<
     (probs * target_probs).mean().negate() * last_axis_numel
 }
 
+/// [Focal loss](https://arxiv.org/abs/1708.02002) for imbalanced classification.
+/// This computes: `-(alpha * (1 - p)^gamma * log(p) * target_probs).sum(-1).mean()`,
+/// where `p = logits.softmax()`.
+///
+/// The modulating factor `(1 - p)^gamma` down-weights well classified examples. With
+/// `gamma = 0` and no `alpha` this is the same as [cross_entropy_with_logits_loss()].
+///
+/// This will call `log_softmax(logits)`, so make sure logits is **not the
+/// output from** [softmax()] or [log_softmax()] already.
+///
+/// # Arguments
+///
+/// - `logits`: The un-normalized output from a model. [log_softmax()] is called **in** this function
+/// - `target_probs`: Target containing probability vectors **NOT** class indices.
+/// - `gamma`: The focusing parameter. Must be `>= 0`.
+/// - `alpha`: Optional per element weights, e.g. per class weights broadcast to the shape of `logits`.
+pub fn focal_loss_with_logits<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>>(
+    logits: Tensor<S, E, D, T>,
+    target_probs: Tensor<S, E, D>,
+    gamma: E,
+    alpha: Option<Tensor<S, E, D>>,
+) -> Tensor<Rank0, E, D, T> {
+    let last_axis_numel = E::from_usize(<S as HasAxes<S::LastAxis>>::size(logits.shape())).unwrap();
+    let log_probs = logits.log_softmax::<S::LastAxis>();
+    let mut loss = if gamma == E::default() {
+        // (1 - p)^0 is 1, but its gradient is NaN where p == 1
+        log_probs * target_probs
+    } else {
+        // clamp so the gradient of powf is finite where p == 1
+        let eps = E::from_f32(1e-7).unwrap();
+        let one_minus_p = (log_probs.retaped::<T>().exp().negate() + E::ONE).clamp(eps, E::ONE);
+        one_minus_p.powf(gamma) * log_probs * target_probs
+    };
+    if let Some(alpha) = alpha {
+        loss = loss * alpha;
+    }
+    loss.mean().negate() * last_axis_numel
+}
+
 /// [KL Divergence loss](https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence).
 /// This computes `(target_probs * (target_probs.log() - logits.log_softmax())).sum(-1).mean()`
 ///
@@ -202,6 +241,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_focal_loss_gamma_zero_is_cross_entropy() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [0.01322946, 0.7367754, -0.8874471, 0.6997109, 0.98312855],
+            [-0.19822043, 1.192167, -0.7495395, -1.5733303, -1.4898887],
+        ]);
+        let y: Tensor<_, TestDtype, _> = dev.tensor([
+            [0.3180433, 0.15164024, 0.2352255, 0.08821669, 0.20687431],
+            [0.15627657, 0.29779273, 0.10897867, 0.2879545, 0.14899758],
+        ]);
+        let ce = cross_entropy_with_logits_loss(x.leaky_trace(), y.clone());
+        let focal = focal_loss_with_logits(x.leaky_trace(), y.clone(), 0.0, None);
+        assert_close(&focal.array(), &ce.array());
+        let ce_g = ce.backward();
+        let focal_g = focal.backward();
+        assert_close(&focal_g.get(&x).array(), &ce_g.get(&x).array());
+    }
+
+    #[test]
+    fn test_focal_loss_down_weights_easy_examples() {
+        let dev: TestDevice = Default::default();
+        let easy: Tensor<_, TestDtype, _> = dev.tensor([4.0, -2.0, -2.0]);
+        let hard: Tensor<_, TestDtype, _> = dev.tensor([-1.0, 0.5, 0.5]);
+        let targ: Tensor<_, TestDtype, _> = dev.tensor([1.0, 0.0, 0.0]);
+
+        let ratio = |x: Tensor<Rank1<3>, TestDtype, TestDevice>| {
+            let ce = cross_entropy_with_logits_loss(x.clone(), targ.clone()).array();
+            let focal = focal_loss_with_logits(x, targ.clone(), 2.0, None).array();
+            focal / ce
+        };
+        let easy_ratio = ratio(easy);
+        let hard_ratio = ratio(hard.clone());
+        assert!(easy_ratio < 1e-3);
+        assert!(hard_ratio > 0.5);
+
+        let alpha = dev.tensor([0.25, 0.25, 0.25]);
+        let weighted = focal_loss_with_logits(hard.clone(), targ.clone(), 2.0, Some(alpha));
+        let unweighted = focal_loss_with_logits(hard, targ, 2.0, None);
+        assert_close(&weighted.array(), &(unweighted.array() * 0.25));
+    }
+
+    #[test]
+    fn test_focal_loss_saturated_logits_grad() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[100.0, 0.0, 0.0]]);
+        let y: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 0.0, 0.0]]);
+        let ce_g = cross_entropy_with_logits_loss(x.leaky_trace(), y.clone()).backward();
+        for gamma in [0.0, 0.5, 2.0] {
+            let loss = focal_loss_with_logits(x.leaky_trace(), y.clone(), gamma, None);
+            assert!(loss.array().is_finite());
+            let g = loss.backward().get(&x).array();
+            assert!(g[0].iter().all(|v| v.is_finite()), "gamma={gamma}: {g:?}");
+            if gamma == 0.0 {
+                assert_close(&g, &ce_g.get(&x).array());
+            }
+        }
+    }
+
+    #[test]
+    fn test_focal_loss_grad() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[-1.0, 0.5, 0.5], [2.0, 0.0, -1.0]]);
+        let y: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        let err = check_gradient(
+            |x| focal_loss_with_logits(x, y.clone(), 2.0, None),
+            &x,
+            1e-3,
+        );
+        assert!(err < 1e-3, "{err}");
+    }
+
     #[test]
     fn test_kl_div() {
         let dev: TestDevice = Default::default();
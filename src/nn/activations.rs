@@ -31,6 +31,8 @@ activation_impls!(Cos, try_cos, #[doc="Calls [cos()]."]);
 activation_impls!(Ln, try_ln, #[doc="Calls [ln()]."]);
 activation_impls!(Exp, try_exp, #[doc="Calls [exp()]."]);
 activation_impls!(Sigmoid, try_sigmoid, #[doc="Calls [sigmoid()]."]);
+activation_impls!(Softplus, try_softplus, #[doc="Calls [softplus()]."]);
+activation_impls!(Mish, try_mish, #[doc="Calls [mish()]."]);
 activation_impls!(Tanh, try_tanh, #[doc="Calls [tanh()]."]);
 activation_impls!(Square, try_square, #[doc="Calls [square()]."]);
 activation_impls!(Sqrt, try_sqrt, #[doc="Calls [sqrt()]."]);
@@ -139,6 +141,24 @@ mod tests {
         let r2 = sigmoid(t);
         assert_eq!(r1.array(), r2.array());
     }
+
+    #[test]
+    fn test_nn_activations_softplus() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r1 = Softplus.forward_mut(t.clone());
+        let r2 = softplus(t);
+        assert_eq!(r1.array(), r2.array());
+    }
+
+    #[test]
+    fn test_nn_activations_mish() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r1 = Mish.forward_mut(t.clone());
+        let r2 = mish(t);
+        assert_eq!(r1.array(), r2.array());
+    }
     #[test]
     fn test_nn_activations_tanh() {
         let dev: TestDevice = Default::default();
@@ -405,6 +405,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_batchnorm2d_train_vs_eval() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<4, 2, 3, 3>, TestDtype, _> = dev.sample_normal();
+        let mut bn = dev.build_module::<BatchNorm2D<2>, TestDtype>();
+        bn.running_mean = dev.tensor([0.5, -1.0]);
+        bn.running_var = dev.tensor([4.0, 0.25]);
+        bn.scale = dev.tensor([2.0, 1.0]);
+        bn.bias = dev.tensor([0.0, 3.0]);
+        bn.epsilon = 0.0;
+
+        // eval mode uses the stored stats
+        let y = bn.forward(x.clone()).array();
+        let x_arr = x.array();
+        for b in 0..4 {
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert_close(&y[b][0][i][j], &((x_arr[b][0][i][j] - 0.5) / 2.0 * 2.0));
+                    assert_close(&y[b][1][i][j], &((x_arr[b][1][i][j] + 1.0) / 0.5 + 3.0));
+                }
+            }
+        }
+
+        // training mode normalizes with the stats of the current batch
+        let y = bn.forward_mut(x.leaky_trace());
+        let y = (y - bn.bias.clone().broadcast()) / bn.scale.clone().broadcast();
+        let mean = y.retaped::<NoneTape>().mean::<Rank1<2>, _>();
+        let var = y.square().mean::<Rank1<2>, _>() - mean.clone().square();
+        assert_close_with_tolerance(&mean.array(), &[0.0; 2], 1e-5);
+        assert_close_with_tolerance(&var.array(), &[1.0; 2], 1e-4);
+    }
+
     #[test]
     fn test_batchnorm2d_update() {
         let dev: TestDevice = Default::default();
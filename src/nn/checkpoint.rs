@@ -0,0 +1,149 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{shapes::*, tensor::*, tensor_ops::Device};
+
+use super::Module;
+
+/// Runs `module` on `x` without storing any of its intermediate activations,
+/// and instead re-runs `module` during the backward pass to compute gradients.
+///
+/// This trades compute for memory: only `x` and the output are kept alive
+/// between forward and backward, at the cost of running `module` twice.
+///
+/// **`module` must be deterministic**, otherwise the recomputed forward will
+/// not match the original forward. Since this uses [Module::forward()],
+/// modules like [super::modules::Dropout] are already no-ops.
+///
+/// Gradients for `x` and all of `module`'s parameters are identical to a
+/// non-checkpointed forward.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// use dfdx::nn::checkpoint;
+/// type Block = (Linear<5, 5>, ReLU, Linear<5, 5>, ReLU);
+/// let model = dev.build_module::<Block, f32>();
+/// let x: Tensor<Rank1<5>, f32, _> = dev.sample_normal();
+/// let y = checkpoint(&model, x.leaky_trace());
+/// let grads = y.sum().backward();
+/// let _ = grads.get(&model.0.weight);
+/// ```
+pub fn checkpoint<M, S: Shape, S2: Shape, E: Dtype, D: Device<E>>(
+    module: &M,
+    x: Tensor<S, E, D, OwnedTape<E, D>>,
+) -> Tensor<S2, E, D, OwnedTape<E, D>>
+where
+    M: 'static
+        + Clone
+        + Module<Tensor<S, E, D>, Output = Tensor<S2, E, D>, Error = D::Err>
+        + Module<
+            Tensor<S, E, D, OwnedTape<E, D>>,
+            Output = Tensor<S2, E, D, OwnedTape<E, D>>,
+            Error = D::Err,
+        >,
+{
+    try_checkpoint(module, x).unwrap()
+}
+
+/// Fallible version of [checkpoint()]
+pub fn try_checkpoint<M, S: Shape, S2: Shape, E: Dtype, D: Device<E>>(
+    module: &M,
+    x: Tensor<S, E, D, OwnedTape<E, D>>,
+) -> Result<Tensor<S2, E, D, OwnedTape<E, D>>, D::Err>
+where
+    M: 'static
+        + Clone
+        + Module<Tensor<S, E, D>, Output = Tensor<S2, E, D>, Error = D::Err>
+        + Module<
+            Tensor<S, E, D, OwnedTape<E, D>>,
+            Output = Tensor<S2, E, D, OwnedTape<E, D>>,
+            Error = D::Err,
+        >,
+{
+    let (x, mut tape) = x.split_tape();
+    let out: Tensor<S2, E, D> = module.try_forward(x.clone())?;
+    let out_ghost = out.ghost();
+    let module = module.clone();
+    tape.add_backward_op(move |grads| {
+        let recomputed = module.try_forward(x.put_tape(OwnedTape::<E, D>::default()))?;
+        let (recomputed, inner_tape) = recomputed.split_tape();
+        grads.try_copy_for(&out_ghost, &recomputed.ghost())?;
+        inner_tape.execute_into(grads)
+    });
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::builders::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_checkpoint_matches_normal_grads() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<3, 5>, Tanh, Linear<5, 5>, ReLU, Linear<5, 2>);
+        let model = dev.build_module::<Model, TestDtype>();
+        let x: Tensor<Rank2<4, 3>, TestDtype, _> = dev.sample_normal();
+
+        let y1 = model.forward(x.leaky_trace());
+        let y2 = checkpoint(&model, x.leaky_trace());
+        assert_eq!(y1.array(), y2.array());
+
+        let g1 = (y1 * 2.0).exp().mean().backward();
+        let g2 = (y2 * 2.0).exp().mean().backward();
+        assert_close(&g1.get(&x).array(), &g2.get(&x).array());
+        assert_close(
+            &g1.get(&model.0.weight).array(),
+            &g2.get(&model.0.weight).array(),
+        );
+        assert_close(
+            &g1.get(&model.0.bias).array(),
+            &g2.get(&model.0.bias).array(),
+        );
+        assert_close(
+            &g1.get(&model.2.weight).array(),
+            &g2.get(&model.2.weight).array(),
+        );
+        assert_close(
+            &g1.get(&model.4.weight).array(),
+            &g2.get(&model.4.weight).array(),
+        );
+        assert_close(
+            &g1.get(&model.4.bias).array(),
+            &g2.get(&model.4.bias).array(),
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_stores_fewer_ops() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<3, 5>, Tanh, Linear<5, 5>, ReLU, Linear<5, 2>);
+        let model = dev.build_module::<Model, TestDtype>();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let (_, normal_tape) = model.forward(x.leaky_trace()).split_tape();
+        let (_, ckpt_tape) = checkpoint(&model, x.leaky_trace()).split_tape();
+        assert!(normal_tape.operations.len() > 1);
+        assert_eq!(ckpt_tape.operations.len(), 1);
+    }
+
+    #[test]
+    fn test_checkpoint_composes_with_outer_graph() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Linear<3, 3>, TestDtype>();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let y1 = model.forward(model.forward(x.leaky_trace().square()).relu());
+        let y2 = checkpoint(&model, checkpoint(&model, x.leaky_trace().square()).relu());
+        assert_close(&y1.array(), &y2.array());
+
+        let g1 = y1.sum().backward();
+        let g2 = y2.sum().backward();
+        assert_close(&g1.get(&x).array(), &g2.get(&x).array());
+        assert_close(
+            &g1.get(&model.weight).array(),
+            &g2.get(&model.weight).array(),
+        );
+        assert_close(&g1.get(&model.bias).array(), &g2.get(&model.bias).array());
+    }
+}
@@ -36,6 +36,17 @@ impl<E: Dtype, D: Device<E>> TensorVisitor<E, D> for ModelEMAOp<E> {
 /// let mut model_ema = model.clone();
 /// model_ema.ema(&model, 0.001);
 /// ```
+///
+/// The EMA copy is a regular module, so it can be swapped in for evaluation and back:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// # let mut model = dev.build_module::<Linear<2, 5>, f32>();
+/// # let mut model_ema = model.clone();
+/// std::mem::swap(&mut model, &mut model_ema);
+/// // evaluate `model`, which now holds the EMA weights
+/// std::mem::swap(&mut model, &mut model_ema);
+/// ```
 pub trait ModelEMA<E: Dtype, D: Device<E>>: TensorCollection<E, D> {
     /// Does `self = self * decay + other * (1 - decay), using
     /// [crate::tensor_ops::axpy()] on parameters.
@@ -120,4 +131,25 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_model_ema_lags_by_decay() {
+        let dev: TestDevice = Default::default();
+        type Model = Linear<3, 5>;
+        let model = dev.build_module::<Model, TestDtype>();
+        let mut ema = dev.build_module::<Model, TestDtype>();
+        let ema0 = ema.clone();
+
+        let decay: TestDtype = 0.9;
+        for _ in 0..5 {
+            ema.ema(&model, decay);
+        }
+
+        // (ema - model) should shrink by a factor of `decay` every update
+        let lag = decay.powi(5);
+        let expected = (ema0.weight.clone() - model.weight.clone()) * lag + model.weight.clone();
+        assert_close(&ema.weight.array(), &expected.array());
+        let expected = (ema0.bias.clone() - model.bias.clone()) * lag + model.bias.clone();
+        assert_close(&ema.bias.array(), &expected.array());
+    }
 }
@@ -0,0 +1,13 @@
+use crate::{shapes::Dtype, tensor::Cpu};
+
+impl<E: Dtype> super::KvCacheKernel<E> for Cpu {
+    fn write(
+        &self,
+        dst: &mut Self::Vec<E>,
+        offset: usize,
+        src: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        dst[offset..offset + src.len()].copy_from_slice(src);
+        Ok(())
+    }
+}
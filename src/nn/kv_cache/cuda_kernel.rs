@@ -0,0 +1,15 @@
+use crate::{shapes::Dtype, tensor::Cuda};
+use cudarc::driver::DeviceSlice;
+
+impl<E: Dtype> super::KvCacheKernel<E> for Cuda {
+    fn write(
+        &self,
+        dst: &mut Self::Vec<E>,
+        offset: usize,
+        src: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        self.dev
+            .dtod_copy(src, &mut dst.slice_mut(offset..offset + src.len()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,316 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    shapes::*,
+    tensor::*,
+    tensor_ops::{Device, ReshapeTo, TryConcat},
+};
+
+use std::{sync::Arc, vec::Vec};
+
+mod cpu_kernel;
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+pub trait KvCacheKernel<E: Dtype>: DeviceStorage {
+    /// Copies all of `src` into `dst`, starting at element `offset` of `dst`.
+    fn write(
+        &self,
+        dst: &mut Self::Vec<E>,
+        offset: usize,
+        src: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// The number of timesteps a layer's buffers grow by when they are full.
+const CHUNK_SIZE: usize = 64;
+
+/// Stores the keys & values of previous timesteps for each attention layer,
+/// for use in autoregressive decoding.
+///
+/// The sequence must be the first dimension of `S`, and must be a [usize]. For example
+/// `(usize, Const<M>)` for unbatched inputs, or `(usize, B, Const<M>)` for batched inputs.
+///
+/// Each layer keeps buffers with room for more timesteps than are cached, and new
+/// timesteps are written into the free space. When a buffer is full, it grows by a
+/// fixed number of timesteps, so appending doesn't copy the whole cache every time.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// use dfdx::nn::KvCache;
+/// let mut cache: KvCache<(usize, Const<4>), f32, Cpu> = KvCache::new(2);
+///
+/// // prompt of 3 tokens
+/// let k = dev.zeros_like(&(3, Const));
+/// let v = dev.zeros_like(&(3, Const));
+/// let (k, v) = cache.append(0, k, v);
+/// assert_eq!(k.shape().0, 3);
+///
+/// // 1 new token
+/// let (k, v) = cache.append(0, dev.zeros_like(&(1, Const)), dev.zeros_like(&(1, Const)));
+/// assert_eq!(k.shape().0, 4);
+/// assert_eq!(cache.seq_len(0), 4);
+/// assert_eq!(cache.seq_len(1), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KvCache<S: Shape, E: Dtype, D: Device<E>> {
+    layers: Vec<Option<CachedLayer<S, E, D>>>,
+}
+
+/// The buffers of one layer, with one row per timestep. Only the first
+/// `shape.concrete()[0]` rows are filled.
+#[derive(Debug, Clone)]
+struct CachedLayer<S: Shape, E: Dtype, D: Device<E>> {
+    k: Tensor<(usize, usize), E, D>,
+    v: Tensor<(usize, usize), E, D>,
+    shape: S,
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>> CachedLayer<S, E, D> {
+    fn len(&self) -> usize {
+        self.shape.concrete()[0]
+    }
+
+    fn capacity(&self) -> usize {
+        self.k.shape().0
+    }
+
+    /// Returns copies of the first `shape.concrete()[0]` rows of the buffers.
+    fn try_filled(&self, shape: S) -> Result<(Tensor<S, E, D>, Tensor<S, E, D>), D::Err> {
+        let len = shape.concrete()[0];
+        let k = self.k.clone().try_slice((0..len, ..))?;
+        let v = self.v.clone().try_slice((0..len, ..))?;
+        Ok((
+            k.try_reshape_like(&shape).unwrap()?,
+            v.try_reshape_like(&shape).unwrap()?,
+        ))
+    }
+}
+
+/// Copies `t` into row major order, unless it already is.
+fn try_contiguous<S: Shape, E: Dtype, D: Device<E>>(
+    t: Tensor<S, E, D>,
+) -> Result<Tensor<S, E, D>, D::Err> {
+    if t.strides == t.shape.strides() {
+        Ok(t)
+    } else {
+        let shape = t.shape;
+        t.try_reshape_like(&shape).unwrap()
+    }
+}
+
+/// The bound on [TryConcat] ensures the sequence dimension is a [usize].
+impl<S: Shape, E: Dtype, D: Device<E> + KvCacheKernel<E>> KvCache<S, E, D>
+where
+    Tensor<S, E, D>: TryConcat<Tensor<S, E, D>, Output = Tensor<S, E, D>, Err = D::Err>,
+{
+    /// Creates an empty cache for `num_layers` attention layers.
+    pub fn new(num_layers: usize) -> Self {
+        Self {
+            layers: (0..num_layers).map(|_| None).collect(),
+        }
+    }
+
+    /// The number of layers this cache was created with.
+    pub fn num_layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// The number of timesteps cached for `layer`.
+    pub fn seq_len(&self, layer: usize) -> usize {
+        self.layers[layer].as_ref().map_or(0, |l| l.len())
+    }
+
+    /// The number of timesteps `layer` can hold before its buffers need to grow.
+    pub fn capacity(&self, layer: usize) -> usize {
+        self.layers[layer].as_ref().map_or(0, |l| l.capacity())
+    }
+
+    /// Appends the keys & values of new timesteps to `layer`, and returns
+    /// all cached keys & values for that layer.
+    ///
+    /// All dimensions of `k` and `v` except the first must match the timesteps
+    /// already cached for `layer`. Non-contiguous `k` and `v` are copied first.
+    pub fn append(
+        &mut self,
+        layer: usize,
+        k: Tensor<S, E, D>,
+        v: Tensor<S, E, D>,
+    ) -> (Tensor<S, E, D>, Tensor<S, E, D>) {
+        self.try_append(layer, k, v).unwrap()
+    }
+
+    /// Fallible version of [KvCache::append]. If an error is returned, the
+    /// cached timesteps of `layer` are unchanged.
+    pub fn try_append(
+        &mut self,
+        layer: usize,
+        k: Tensor<S, E, D>,
+        v: Tensor<S, E, D>,
+    ) -> Result<(Tensor<S, E, D>, Tensor<S, E, D>), D::Err> {
+        assert_eq!(k.shape, v.shape, "keys and values must have the same shape");
+        let k = try_contiguous(k)?;
+        let v = try_contiguous(v)?;
+        let mut dims = k.shape.concrete();
+        let row_len: usize = dims.into_iter().skip(1).product();
+
+        let mut new_layer = None;
+        let cached = match self.layers[layer].as_mut() {
+            Some(cached) => {
+                assert!(
+                    dims.into_iter()
+                        .skip(1)
+                        .eq(cached.shape.concrete().into_iter().skip(1)),
+                    "appended timesteps have shape {:?}, but cached timesteps have shape {:?}",
+                    k.shape,
+                    cached.shape,
+                );
+                cached
+            }
+            None => {
+                dims[0] = 0;
+                new_layer.insert(CachedLayer {
+                    k: k.device.try_zeros_like(&(0, row_len))?,
+                    v: k.device.try_zeros_like(&(0, row_len))?,
+                    shape: S::from_concrete(&dims).unwrap(),
+                })
+            }
+        };
+        let len = cached.len();
+        dims[0] = len + k.shape.concrete()[0];
+
+        // nothing below changes the filled rows, so on error the cache is still valid
+        if dims[0] > cached.capacity() {
+            let extra = (dims[0] - cached.capacity()).div_ceil(CHUNK_SIZE) * CHUNK_SIZE;
+            let zeros: Tensor<(usize, usize), E, D> = k.device.try_zeros_like(&(extra, row_len))?;
+            let k_buf = cached.k.clone().try_concat(zeros.clone())?;
+            let v_buf = cached.v.clone().try_concat(zeros)?;
+            cached.k = k_buf;
+            cached.v = v_buf;
+        }
+        let offset = len * row_len;
+        k.device
+            .write(Arc::make_mut(&mut cached.k.data), offset, k.data.as_ref())?;
+        v.device
+            .write(Arc::make_mut(&mut cached.v.data), offset, v.data.as_ref())?;
+
+        let shape = S::from_concrete(&dims).unwrap();
+        let filled = cached.try_filled(shape)?;
+        cached.shape = shape;
+        if let Some(cached) = new_layer {
+            self.layers[layer] = Some(cached);
+        }
+        Ok(filled)
+    }
+
+    /// Returns all cached keys & values for `layer`, or `None` if nothing has been
+    /// appended to it yet.
+    pub fn get(&self, layer: usize) -> Option<(Tensor<S, E, D>, Tensor<S, E, D>)> {
+        self.layers[layer]
+            .as_ref()
+            .map(|cached| cached.try_filled(cached.shape).unwrap())
+    }
+
+    /// Removes all cached timesteps from every layer.
+    pub fn clear(&mut self) {
+        for layer in self.layers.iter_mut() {
+            *layer = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_kv_cache_append_matches_concat() {
+        let dev: TestDevice = Default::default();
+        type S = (usize, Const<2>, Const<3>);
+        let mut cache: KvCache<S, TestDtype, TestDevice> = KvCache::new(2);
+        assert!(cache.get(0).is_none());
+
+        let k1: Tensor<S, TestDtype, _> = dev.sample_normal_like(&(3, Const, Const));
+        let v1: Tensor<S, TestDtype, _> = dev.sample_normal_like(&(3, Const, Const));
+        let k2: Tensor<S, TestDtype, _> = dev.sample_normal_like(&(2, Const, Const));
+        let v2: Tensor<S, TestDtype, _> = dev.sample_normal_like(&(2, Const, Const));
+
+        let (k, v) = cache.append(1, k1.clone(), v1.clone());
+        assert_eq!(k.as_vec(), k1.as_vec());
+        assert_eq!(v.as_vec(), v1.as_vec());
+
+        let (k, v) = cache.append(1, k2.clone(), v2.clone());
+        assert_eq!(k.shape(), &(5, Const, Const));
+        assert_eq!(k.as_vec(), k1.clone().concat(k2).as_vec());
+        assert_eq!(v.as_vec(), v1.clone().concat(v2).as_vec());
+
+        let (k_get, v_get) = cache.get(1).unwrap();
+        assert_eq!(k_get.as_vec(), k.as_vec());
+        assert_eq!(v_get.as_vec(), v.as_vec());
+        assert_eq!(cache.seq_len(1), 5);
+        assert_eq!(cache.seq_len(0), 0);
+
+        cache.clear();
+        assert_eq!(cache.seq_len(1), 0);
+    }
+
+    #[test]
+    fn test_kv_cache_grows_by_chunks() {
+        let dev: TestDevice = Default::default();
+        type S = (usize, Const<2>);
+        let mut cache: KvCache<S, TestDtype, TestDevice> = KvCache::new(1);
+
+        let mut k_all: std::vec::Vec<TestDtype> = std::vec::Vec::new();
+        let mut v_all: std::vec::Vec<TestDtype> = std::vec::Vec::new();
+        for step in 0..CHUNK_SIZE + 2 {
+            let k: Tensor<S, TestDtype, _> = dev.sample_normal_like(&(1, Const));
+            let v: Tensor<S, TestDtype, _> = dev.sample_normal_like(&(1, Const));
+            k_all.extend(k.as_vec());
+            v_all.extend(v.as_vec());
+
+            let (k, v) = cache.append(0, k, v);
+            assert_eq!(k.shape(), &(step + 1, Const));
+            assert_eq!(k.as_vec(), k_all);
+            assert_eq!(v.as_vec(), v_all);
+
+            let expected_capacity = if step < CHUNK_SIZE {
+                CHUNK_SIZE
+            } else {
+                2 * CHUNK_SIZE
+            };
+            assert_eq!(cache.capacity(0), expected_capacity);
+        }
+        assert_eq!(cache.seq_len(0), CHUNK_SIZE + 2);
+    }
+
+    #[test]
+    fn test_kv_cache_non_contiguous() {
+        let dev: TestDevice = Default::default();
+        type S = (usize, Const<4>);
+        let mut cache: KvCache<S, TestDtype, TestDevice> = KvCache::new(1);
+
+        let k1: Tensor<(Const<4>, usize), TestDtype, _> = dev.sample_normal_like(&(Const, 3));
+        let k1: Tensor<S, TestDtype, _> = k1.permute::<_, Axes2<1, 0>>();
+        let v1: Tensor<Rank1<4>, TestDtype, _> = dev.sample_normal();
+        let v1: Tensor<S, TestDtype, _> = v1.broadcast_like(&(3, Const));
+
+        let (k, v) = cache.append(0, k1.clone(), v1.clone());
+        assert_eq!(k.as_vec(), k1.as_vec());
+        assert_eq!(v.as_vec(), v1.as_vec());
+
+        let k2: Tensor<S, TestDtype, _> = dev.sample_normal_like(&(1, Const));
+        let (k, _) = cache.append(0, k2.clone(), k2.clone());
+        assert_eq!(k.as_vec(), [k1.as_vec(), k2.as_vec()].concat());
+    }
+
+    #[test]
+    #[should_panic = "appended timesteps have shape (1, 4), but cached timesteps have shape (2, 3)"]
+    fn test_kv_cache_wrong_row_shape() {
+        let dev: TestDevice = Default::default();
+        let mut cache: KvCache<(usize, usize), TestDtype, TestDevice> = KvCache::new(1);
+        cache.append(0, dev.zeros_like(&(2, 3)), dev.zeros_like(&(2, 3)));
+        cache.append(0, dev.zeros_like(&(1, 4)), dev.zeros_like(&(1, 4)));
+    }
+}
@@ -180,4 +180,15 @@ mod tests {
         );
         assert_close(&g.get(&m.beta).array(), &[0.2; 5]);
     }
+
+    #[test]
+    fn test_layer_norm_epsilon() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<builder::LayerNorm1D<5>, TestDtype>();
+        // variance of 1.6e-7 is small compared to the default epsilon of 1e-5
+        let x: Tensor<Rank1<5>, TestDtype, _> = dev.tensor([1.0, 1.0, 1.0, 1.0, 1.001]);
+        assert!(m.forward(x.clone()).array()[4] < 0.5);
+        m.epsilon = 1e-12;
+        assert!(m.forward(x).array()[4] > 1.5);
+    }
 }
@@ -104,6 +104,20 @@
 //! model.reset_params();
 //! ```
 //!
+//! # Checkpointing
+//!
+//! Use [checkpoint()] to avoid storing the intermediate activations of a module, and
+//! instead recompute them during the backward pass:
+//!
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! type Block = (Linear<5, 5>, ReLU);
+//! let model = dev.build_module::<Block, f32>();
+//! let x: Tensor<Rank1<5>, f32, _> = dev.zeros();
+//! let y = dfdx::nn::checkpoint(&model, x.leaky_trace());
+//! ```
+//!
 //! # Sequential models
 //!
 //! Tuple's implement [Module], so you can string multiple module's together.
@@ -174,6 +188,7 @@
 //! ```
 
 mod build_module;
+mod checkpoint;
 mod num_params;
 mod reset_params;
 pub mod tensor_collection;
@@ -196,6 +211,7 @@ mod embedding;
 mod flatten;
 mod generalized_residual;
 mod impl_module_for_tuples;
+mod kv_cache;
 mod layer_norm;
 mod linear;
 #[cfg(feature = "numpy")]
@@ -222,7 +238,9 @@ pub use tensor_collection::*;
 
 #[cfg(feature = "safetensors")]
 pub use self::safetensors::{LoadFromSafetensors, SaveToSafetensors};
+pub use checkpoint::{checkpoint, try_checkpoint};
 pub use ema::ModelEMA;
+pub use kv_cache::KvCache;
 #[cfg(feature = "numpy")]
 pub use npz::{LoadFromNpz, SaveToNpz};
 pub use num_params::NumParams;
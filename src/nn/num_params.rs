@@ -21,6 +21,23 @@ impl<E: Dtype, D: Device<E>> TensorVisitor<E, D> for Counter {
     }
 }
 
+struct ByteCounter(usize);
+impl<E: Dtype, D: Device<E>> TensorVisitor<E, D> for ByteCounter {
+    type Viewer = ViewTensorRef;
+    type Err = D::Err;
+    type E2 = E;
+    type D2 = D;
+
+    fn visit<S: Shape>(
+        &mut self,
+        _: TensorOptions<S, E, D>,
+        t: &Tensor<S, E, D>,
+    ) -> Result<Option<Tensor<S, E, D>>, Self::Err> {
+        self.0 += t.shape().num_elements() * std::mem::size_of::<E>();
+        Ok(None)
+    }
+}
+
 /// Get the number of trainable parameters in a model.
 ///
 /// ```rust
@@ -29,6 +46,7 @@ impl<E: Dtype, D: Device<E>> TensorVisitor<E, D> for Counter {
 /// type Model = Linear<2, 5>;
 /// let model = dev.build_module::<Model, f32>();
 /// assert_eq!(model.num_trainable_params(), 2 * 5 + 5);
+/// assert_eq!(model.num_bytes(), (2 * 5 + 5) * 4);
 /// ```
 pub trait NumParams<E: Dtype, D: Device<E>>: TensorCollection<E, D> {
     /// Returns the number of trainable params in any model.
@@ -41,5 +59,36 @@ pub trait NumParams<E: Dtype, D: Device<E>>: TensorCollection<E, D> {
         .unwrap();
         op.0
     }
+
+    /// Returns the number of bytes taken up by all tensors in the model, including
+    /// tensors that are not trained, like [super::BatchNorm2D]'s running statistics.
+    fn num_bytes(&self) -> usize {
+        let mut op = ByteCounter(0);
+        Self::iter_tensors(&mut RecursiveWalker {
+            m: self,
+            f: &mut op,
+        })
+        .unwrap();
+        op.0
+    }
 }
 impl<E: Dtype, D: Device<E>, M: TensorCollection<E, D>> NumParams<E, D> for M {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::builders::*, tests::*};
+
+    #[test]
+    fn test_num_params_and_bytes() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<3, 4>, BatchNorm1D<4>, Linear<4, 2>);
+        let m = dev.build_module::<Model, TestDtype>();
+        let trainable = (3 * 4 + 4) + (4 + 4) + (4 * 2 + 2);
+        assert_eq!(m.num_trainable_params(), trainable);
+        assert_eq!(
+            m.num_bytes(),
+            (trainable + 4 + 4) * std::mem::size_of::<TestDtype>()
+        );
+    }
+}
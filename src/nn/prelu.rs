@@ -40,13 +40,13 @@ impl<E: Dtype, D: Device<E>> NonMutableModule for PReLU<E, D> {}
 impl<S: ConstShape, E: Dtype, D: Device<E>, T: Tape<E, D>> Module<Tensor<S, E, D, T>>
     for PReLU<E, D>
 where
-    Tensor<S, E, D, T>: TryPReLU<Tensor<S, E, D, NoneTape>>,
+    Tensor<S, E, D, T>: TryPReLU<Tensor<S, E, D, T>>,
 {
     type Output = Tensor<S, E, D, T>;
     type Error = <Tensor<S, E, D, T> as HasErr>::Err;
 
     fn try_forward(&self, input: Tensor<S, E, D, T>) -> Result<Self::Output, Self::Error> {
-        input.try_prelu(self.a.retaped().broadcast())
+        input.try_prelu(self.a.retaped::<T>().broadcast())
     }
 }
 
@@ -84,28 +84,28 @@ impl<C: ConstDim, E: Dtype, D: Device<E>> NonMutableModule for PReLU1D<C, E, D>
 impl<C: ConstDim, E: Dtype, D: Device<E>, T: Tape<E, D>> Module<Tensor<(C,), E, D, T>>
     for PReLU1D<C, E, D>
 where
-    Tensor<(C,), E, D, T>: TryPReLU<Tensor<(C,), E, D, NoneTape>>,
+    Tensor<(C,), E, D, T>: TryPReLU<Tensor<(C,), E, D, T>>,
 {
     type Output = Tensor<(C,), E, D, T>;
 
     type Error = <Tensor<(C,), E, D, T> as HasErr>::Err;
 
     fn try_forward(&self, input: Tensor<(C,), E, D, T>) -> Result<Self::Output, Self::Error> {
-        input.try_prelu(self.a.retaped())
+        input.try_prelu(self.a.retaped::<T>())
     }
 }
 
 macro_rules! prelu1d {
     (($($InDims:tt),*), $Axes:ty) => {
-        impl<E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<NoneTape>, $($InDims: ConstDim),*> Module<Tensor<($($InDims),*), E, D, T>> for PReLU1D<C,E, D>
+        impl<E: Dtype, D: Device<E>, T: Tape<E, D>, $($InDims: ConstDim),*> Module<Tensor<($($InDims),*), E, D, T>> for PReLU1D<C,E, D>
         where ($($InDims),*): ReduceShapeTo<(C,), $Axes>,
-        Tensor<($($InDims),*), E, D, T>: TryPReLU<Tensor<($($InDims),*), E, D, NoneTape>>,
+        Tensor<($($InDims),*), E, D, T>: TryPReLU<Tensor<($($InDims),*), E, D, T>>,
         {
             type Output = Tensor<($($InDims),*), E, D, T>;
             type Error = <Tensor<($($InDims),*), E, D, T> as HasErr>::Err;
 
             fn try_forward(&self, input: Tensor<($($InDims),*), E, D, T>) -> Result<Self::Output, Self::Error> {
-                input.try_prelu(self.a.retaped().broadcast())
+                input.try_prelu(self.a.retaped::<T>().broadcast())
             }
         }
     };
@@ -134,7 +134,7 @@ mod tests {
 
     use crate::{
         nn::*,
-        tests::{assert_close, TestDevice},
+        tests::{assert_close, TestDevice, TestDtype},
     };
 
     use super::*;
@@ -202,4 +202,23 @@ mod tests {
             &[-0.04820138, -0.03807970, 0.0, 0.76159415, 0.96402758],
         )
     }
+
+    #[test]
+    fn test_nn_prelu_learnable() {
+        let dev: TestDevice = Default::default();
+        let m = PReLU1D::<Const<3>, TestDtype, _> {
+            a: dev.tensor([0.05, 0.1, 0.2]),
+        };
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> =
+            dev.tensor([[-1.0, 2.0, -3.0], [4.0, -5.0, -6.0]]);
+        let r = m.forward(x.leaky_trace());
+        assert_close(&r.array(), &[[-0.05, 2.0, -0.6], [4.0, -0.5, -1.2]]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[0.05, 1.0, 0.2], [1.0, 0.1, 0.2]]);
+        assert_close(&g.get(&m.a).array(), &[-1.0, -5.0, -9.0]);
+
+        let m = PReLU::<TestDtype, _> { a: dev.tensor(0.1) };
+        let g = m.forward(x.leaky_trace()).sum().backward();
+        assert_close(&g.get(&m.a).array(), &-15.0);
+    }
 }
@@ -43,3 +43,28 @@ pub trait ResetParams<E: Dtype, D: Device<E>>: TensorCollection<E, D> {
     }
 }
 impl<E: Dtype, D: Device<E>, M: TensorCollection<E, D>> ResetParams<E, D> for M {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::builders::*, tests::*};
+
+    #[test]
+    fn test_reset_params() {
+        type Model = (Linear<3, 4>, Linear<4, 2>);
+        let dev = TestDevice::seed_from_u64(0);
+        let mut m = dev.build_module::<Model, TestDtype>();
+        let w = m.0.weight.array();
+        m.reset_params();
+        assert_ne!(m.0.weight.array(), w);
+
+        // models re-initialized from identically seeded devices are identical
+        let mut a = TestDevice::seed_from_u64(1).build_module::<Model, TestDtype>();
+        let mut b = TestDevice::seed_from_u64(1).build_module::<Model, TestDtype>();
+        a.reset_params();
+        b.reset_params();
+        assert_eq!(a.0.weight.array(), b.0.weight.array());
+        assert_eq!(a.1.weight.array(), b.1.weight.array());
+        assert_eq!(a.1.bias.array(), b.1.bias.array());
+    }
+}
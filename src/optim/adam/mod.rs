@@ -3,16 +3,19 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use std::{marker::PhantomData, sync::Arc};
+use std::{collections::BTreeSet, marker::PhantomData, sync::Arc};
 
 use crate::{
     nn::tensor_collection::*,
     shapes::{Dtype, Shape},
-    tensor::{DeviceStorage, Gradients, Tensor},
+    tensor::{DeviceStorage, Gradients, Tensor, UniqueId},
     tensor_ops::Device,
 };
 
-use super::{Optimizer, OptimizerUpdateError, UnusedTensors, WeightDecay};
+use super::{
+    optimizer::{has_non_finite, update_frozen},
+    Optimizer, OptimizerUpdateError, UnusedTensors, WeightDecay,
+};
 
 /// Configuration of hyperparameters for [Adam].
 ///
@@ -75,6 +78,12 @@ pub struct Adam<M, E: Dtype, D: DeviceStorage> {
     /// Hyperparameter configuration
     pub cfg: AdamConfig<E>,
 
+    /// If `true`, parameters whose gradient contains NaN or inf are not updated.
+    /// See [Adam::num_nan_skipped()]. Defaults to `false`.
+    pub skip_nan: bool,
+    nan_skipped: usize,
+    frozen: BTreeSet<UniqueId>,
+
     t: i32,
     moment1: Gradients<E, D>,
     moment2: Gradients<E, D>,
@@ -87,12 +96,40 @@ impl<M, E: Dtype, D: DeviceStorage> Adam<M, E, D> {
     pub fn new(_model: &M, cfg: AdamConfig<E>) -> Self {
         Self {
             cfg,
+            skip_nan: false,
+            nan_skipped: 0,
+            frozen: Default::default(),
             t: 0,
             moment1: Gradients::leaky(),
             moment2: Gradients::leaky(),
             marker: PhantomData,
         }
     }
+
+    /// The number of parameters that were not updated during the last update because
+    /// their gradient contained NaN or inf. Only counted if [Adam::skip_nan] is `true`.
+    pub fn num_nan_skipped(&self) -> usize {
+        self.nan_skipped
+    }
+
+    /// Freezes every tensor in `module`, which can be any part of the model. Frozen
+    /// tensors are not updated, and are allowed to be missing from the gradients, so
+    /// they can be run without a tape to skip computing their gradients.
+    /// See [Adam::unfreeze()].
+    pub fn freeze<N: TensorCollection<E, D>>(&mut self, module: &N)
+    where
+        D: Device<E>,
+    {
+        update_frozen(&mut self.frozen, module, true);
+    }
+
+    /// Undoes [Adam::freeze()] for every tensor in `module`.
+    pub fn unfreeze<N: TensorCollection<E, D>>(&mut self, module: &N)
+    where
+        D: Device<E>,
+    {
+        update_frozen(&mut self.frozen, module, false);
+    }
 }
 
 pub trait AdamKernel<E: Dtype>: DeviceStorage {
@@ -120,12 +157,15 @@ impl<M, D: Device<E>, E: Dtype> TensorVisitor<E, D>
         opts: TensorOptions<S, E, D>,
         p: &mut Tensor<S, E, D>,
     ) -> Result<Option<Tensor<S, E, D>>, Self::Err> {
-        if !opts.do_gradient_update {
+        if !opts.do_gradient_update || self.0.frozen.contains(&p.id) {
             return Ok(None);
         }
         let g = self.1.get_ref_checked(p);
         match g {
             None => self.2.add(p),
+            Some(_) if self.0.skip_nan && has_non_finite(&self.1.get(p)) => {
+                self.0.nan_skipped += 1;
+            }
             Some(g) => {
                 let m_t = self.0.moment1.get_or_alloc_mut(p)?;
                 let v_t = self.0.moment2.get_or_alloc_mut(p)?;
@@ -151,6 +191,7 @@ impl<M: TensorCollection<E, D>, D: Device<E>, E: Dtype> Optimizer<M, D, E> for A
         gradients: &Gradients<E, D>,
     ) -> Result<(), OptimizerUpdateError<D>> {
         self.t = self.t.checked_add(1).unwrap();
+        self.nan_skipped = 0;
         let mut op = (self, gradients, Default::default());
         let result = M::iter_tensors(&mut RecursiveWalker {
             m: module,
@@ -294,6 +335,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_adam_skip_nan() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank1<5>, TestDtype, _> = dev.ones();
+        let mut opt = Adam::new(&t, Default::default());
+        opt.skip_nan = true;
+
+        let g = t.leaky_trace().square().sum().backward();
+        opt.update(&mut t, &g).expect("");
+        let params = t.array();
+        let moment1 = opt.moment1.get(&t).array();
+        let moment2 = opt.moment2.get(&t).array();
+
+        let rate: Tensor<_, TestDtype, _> = dev.tensor([1.0, TestDtype::NAN, 1.0, 1.0, 1.0]);
+        let g = (t.leaky_trace() * rate).sum().backward();
+        opt.update(&mut t, &g).expect("");
+        assert_eq!(opt.num_nan_skipped(), 1);
+        assert_eq!(t.array(), params);
+        assert_eq!(opt.moment1.get(&t).array(), moment1);
+        assert_eq!(opt.moment2.get(&t).array(), moment2);
+    }
+
     #[test]
     fn test_unused_tensors() {
         let dev: TestDevice = Default::default();
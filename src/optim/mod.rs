@@ -8,6 +8,11 @@
 //! - [Adam::new()] with [AdamConfig]
 //! - [RMSprop::new()] with [RMSpropConfig]
 //!
+//! # Learning rate schedules
+//!
+//! A [Schedule] maps a step to a learning rate, which you can assign to the optimizer's
+//! `cfg.lr` before calling [Optimizer::update()]. See [Constant] and [Warmup].
+//!
 //! # Updating network parameters
 //!
 //! This is done via [Optimizer::update()], where you pass in a mutable [crate::nn::Module], and
@@ -33,12 +38,14 @@
 mod adam;
 mod optimizer;
 mod rmsprop;
+mod schedule;
 mod sgd;
 
 pub use adam::{Adam, AdamConfig, AdamKernel};
 pub use optimizer::{Momentum, WeightDecay};
 pub use optimizer::{Optimizer, OptimizerUpdateError, UnusedTensors};
 pub use rmsprop::{RMSprop, RMSpropConfig, RMSpropKernel};
+pub use schedule::{Constant, Schedule, Warmup};
 pub use sgd::{Sgd, SgdConfig, SgdKernel};
 
 pub mod prelude {
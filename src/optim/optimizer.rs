@@ -1,8 +1,12 @@
 use crate::{
+    nn::tensor_collection::*,
     shapes::{Dtype, Shape, Unit},
     tensor::{DeviceStorage, Gradients, Tensor, UniqueId},
+    tensor_ops::Device,
 };
 
+use std::collections::BTreeSet;
+
 /// L2 and decoupled regularization methods
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WeightDecay<E> {
@@ -138,3 +142,47 @@ impl<D: DeviceStorage> Into<Result<(), OptimizerUpdateError<D>>> for UnusedTenso
         }
     }
 }
+
+/// Whether `g` contains any NaN or infinite values. This copies `g` off of the device.
+#[allow(clippy::eq_op)]
+pub(super) fn has_non_finite<S: Shape, E: Dtype, D: DeviceStorage>(g: &Tensor<S, E, D>) -> bool {
+    // `x - x` is NaN for NaN and +/- inf, and 0 otherwise. This also works for integer dtypes.
+    g.as_vec().into_iter().any(|x| {
+        let d = x - x;
+        d != d
+    })
+}
+
+struct CollectIds<'a>(&'a mut BTreeSet<UniqueId>, bool);
+impl<'a, E: Dtype, D: Device<E>> TensorVisitor<E, D> for CollectIds<'a> {
+    type Viewer = ViewTensorRef;
+    type Err = D::Err;
+    type E2 = E;
+    type D2 = D;
+
+    fn visit<S: Shape>(
+        &mut self,
+        _: TensorOptions<S, E, D>,
+        t: &Tensor<S, E, D>,
+    ) -> Result<Option<Tensor<S, E, D>>, Self::Err> {
+        if self.1 {
+            self.0.insert(t.id);
+        } else {
+            self.0.remove(&t.id);
+        }
+        Ok(None)
+    }
+}
+
+/// Inserts (if `insert` is `true`) or removes the [UniqueId] of every tensor in `module`.
+pub(super) fn update_frozen<E: Dtype, D: Device<E>, M: TensorCollection<E, D>>(
+    frozen: &mut BTreeSet<UniqueId>,
+    module: &M,
+    insert: bool,
+) {
+    M::iter_tensors(&mut RecursiveWalker {
+        m: module,
+        f: &mut CollectIds(frozen, insert),
+    })
+    .unwrap();
+}
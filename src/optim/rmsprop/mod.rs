@@ -3,7 +3,7 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use std::{marker::PhantomData, sync::Arc};
+use std::{collections::BTreeSet, marker::PhantomData, sync::Arc};
 
 use crate::{
     nn::tensor_collection::*,
@@ -12,7 +12,10 @@ use crate::{
     tensor_ops::Device,
 };
 
-use super::{Optimizer, OptimizerUpdateError, UnusedTensors, WeightDecay};
+use super::{
+    optimizer::{has_non_finite, update_frozen},
+    Optimizer, OptimizerUpdateError, UnusedTensors, WeightDecay,
+};
 
 /// Configuration of hyperparameters for [RMSprop].
 #[derive(Debug, Clone, Copy)]
@@ -82,6 +85,12 @@ pub struct RMSprop<M, E: Dtype, D: DeviceStorage> {
     /// Hyperparameter configuration
     pub cfg: RMSpropConfig<E>,
 
+    /// If `true`, parameters whose gradient contains NaN or inf are not updated.
+    /// See [RMSprop::num_nan_skipped()]. Defaults to `false`.
+    pub skip_nan: bool,
+    nan_skipped: usize,
+    frozen: BTreeSet<UniqueId>,
+
     step: usize,
     momentums: Gradients<E, D>,
     square_avg: Gradients<E, D>,
@@ -95,6 +104,9 @@ impl<M, E: Dtype, D: DeviceStorage> RMSprop<M, E, D> {
     pub fn new(_model: &M, cfg: RMSpropConfig<E>) -> Self {
         Self {
             cfg,
+            skip_nan: false,
+            nan_skipped: 0,
+            frozen: Default::default(),
             step: 0,
             momentums: Gradients::leaky(),
             square_avg: Gradients::leaky(),
@@ -102,6 +114,31 @@ impl<M, E: Dtype, D: DeviceStorage> RMSprop<M, E, D> {
             marker: PhantomData,
         }
     }
+
+    /// The number of parameters that were not updated during the last update because
+    /// their gradient contained NaN or inf. Only counted if [RMSprop::skip_nan] is `true`.
+    pub fn num_nan_skipped(&self) -> usize {
+        self.nan_skipped
+    }
+
+    /// Freezes every tensor in `module`, which can be any part of the model. Frozen
+    /// tensors are not updated, and are allowed to be missing from the gradients, so
+    /// they can be run without a tape to skip computing their gradients.
+    /// See [RMSprop::unfreeze()].
+    pub fn freeze<N: TensorCollection<E, D>>(&mut self, module: &N)
+    where
+        D: Device<E>,
+    {
+        update_frozen(&mut self.frozen, module, true);
+    }
+
+    /// Undoes [RMSprop::freeze()] for every tensor in `module`.
+    pub fn unfreeze<N: TensorCollection<E, D>>(&mut self, module: &N)
+    where
+        D: Device<E>,
+    {
+        update_frozen(&mut self.frozen, module, false);
+    }
 }
 
 pub trait RMSpropKernel<E: Dtype>: DeviceStorage {
@@ -129,12 +166,15 @@ impl<M, E: Dtype, D: Device<E>> TensorVisitor<E, D>
         opts: TensorOptions<S, E, D>,
         p: &mut Tensor<S, E, D>,
     ) -> Result<Option<Tensor<S, E, D>>, Self::Err> {
-        if !opts.do_gradient_update {
+        if !opts.do_gradient_update || self.0.frozen.contains(&p.id) {
             return Ok(None);
         }
         let g = self.1.get_ref_checked(p);
         match g {
             None => self.2.add(p),
+            Some(_) if self.0.skip_nan && has_non_finite(&self.1.get(p)) => {
+                self.0.nan_skipped += 1;
+            }
             Some(g) => {
                 let m = self.0.momentums.get_or_alloc_mut(p)?;
                 let sa = self.0.square_avg.get_or_alloc_mut(p)?;
@@ -167,6 +207,7 @@ impl<M: TensorCollection<E, D>, D: Device<E> + OneFillStorage<E>, E: Dtype> Opti
         module: &mut M,
         gradients: &Gradients<E, D>,
     ) -> Result<(), OptimizerUpdateError<D>> {
+        self.nan_skipped = 0;
         let mut op = (self, gradients, Default::default());
         let result = M::iter_tensors(&mut RecursiveWalker {
             m: module,
@@ -331,6 +372,35 @@ mod tests {
         test_matches_expected(cfg, EXPECTED);
     }
 
+    #[test]
+    fn test_rmsprop_skip_nan() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank1<5>, TestDtype, _> = dev.ones();
+        let cfg = RMSpropConfig {
+            momentum: Some(0.9),
+            centered: true,
+            ..Default::default()
+        };
+        let mut opt = RMSprop::new(&t, cfg);
+        opt.skip_nan = true;
+
+        let g = t.leaky_trace().square().sum().backward();
+        opt.update(&mut t, &g).expect("");
+        let params = t.array();
+        let momentums = opt.momentums.get(&t).array();
+        let square_avg = opt.square_avg.get(&t).array();
+        let grad_avg = opt.grad_avg.get(&t).array();
+
+        let rate: Tensor<_, TestDtype, _> = dev.tensor([1.0, TestDtype::NAN, 1.0, 1.0, 1.0]);
+        let g = (t.leaky_trace() * rate).sum().backward();
+        opt.update(&mut t, &g).expect("");
+        assert_eq!(opt.num_nan_skipped(), 1);
+        assert_eq!(t.array(), params);
+        assert_eq!(opt.momentums.get(&t).array(), momentums);
+        assert_eq!(opt.square_avg.get(&t).array(), square_avg);
+        assert_eq!(opt.grad_avg.get(&t).array(), grad_avg);
+    }
+
     #[test]
     fn test_unused_tensors() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,109 @@
+use crate::shapes::Dtype;
+
+/// Something that maps a training step to a learning rate.
+///
+/// Schedules don't modify optimizers themselves, instead set the `lr` of the optimizer's
+/// config before each update:
+///
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # type Model = Tensor<Rank0, f32, Cpu>;
+/// # let dev: Cpu = Default::default();
+/// # let model: Model = dev.zeros();
+/// let mut opt: Sgd<Model, f32, Cpu> = Sgd::new(&model, Default::default());
+/// let schedule = Warmup {
+///     warmup_steps: 100,
+///     schedule: Constant(1e-2),
+/// };
+/// for step in 0..10 {
+///     opt.cfg.lr = schedule.lr(step);
+///     // -- snip update --
+/// }
+/// ```
+///
+/// Closures of the form `Fn(usize) -> E` also implement this trait.
+pub trait Schedule<E> {
+    /// The learning rate to use at `step`.
+    fn lr(&self, step: usize) -> E;
+}
+
+impl<E, F: Fn(usize) -> E> Schedule<E> for F {
+    fn lr(&self, step: usize) -> E {
+        (self)(step)
+    }
+}
+
+/// A [Schedule] that always returns the same learning rate.
+#[derive(Debug, Clone, Copy)]
+pub struct Constant<E>(pub E);
+
+impl<E: Copy> Schedule<E> for Constant<E> {
+    fn lr(&self, _step: usize) -> E {
+        self.0
+    }
+}
+
+/// Linearly ramps the learning rate from `0` to `schedule.lr(0)` over the first
+/// `warmup_steps`, and then follows `schedule` (offset by `warmup_steps`).
+///
+/// ```rust
+/// # use dfdx::optim::*;
+/// let schedule = Warmup {
+///     warmup_steps: 4,
+///     schedule: Constant(1.0),
+/// };
+/// assert_eq!(schedule.lr(0), 0.0);
+/// assert_eq!(schedule.lr(2), 0.5);
+/// assert_eq!(schedule.lr(4), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Warmup<S> {
+    /// Number of steps to ramp up the learning rate over.
+    pub warmup_steps: usize,
+
+    /// The schedule to follow after warmup.
+    pub schedule: S,
+}
+
+impl<E: Dtype, S: Schedule<E>> Schedule<E> for Warmup<S> {
+    fn lr(&self, step: usize) -> E {
+        if step < self.warmup_steps {
+            let base = self.schedule.lr(0);
+            base * E::from_usize(step).unwrap() / E::from_usize(self.warmup_steps).unwrap()
+        } else {
+            self.schedule.lr(step - self.warmup_steps)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warmup_constant() {
+        let schedule = Warmup {
+            warmup_steps: 10,
+            schedule: Constant(1e-2),
+        };
+        assert_eq!(schedule.lr(0), 0.0);
+        assert_eq!(schedule.lr(5), 5e-3);
+        assert_eq!(schedule.lr(10), 1e-2);
+        assert_eq!(schedule.lr(1000), 1e-2);
+    }
+
+    #[test]
+    fn test_warmup_tracks_inner_schedule() {
+        let decay = |step: usize| 0.5f64.powi(step as i32);
+        let schedule = Warmup {
+            warmup_steps: 4,
+            schedule: decay,
+        };
+        assert_eq!(schedule.lr(0), 0.0);
+        assert_eq!(schedule.lr(1), 0.25);
+        assert_eq!(schedule.lr(4), 1.0);
+        for step in 4..20 {
+            assert_eq!(schedule.lr(step), decay(step - 4));
+        }
+    }
+}
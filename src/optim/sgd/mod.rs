@@ -3,12 +3,12 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use std::marker::PhantomData;
+use std::{collections::BTreeSet, marker::PhantomData};
 
 use crate::{
     nn::tensor_collection::*,
     shapes::{Dtype, Shape},
-    tensor::{DeviceStorage, Gradients, Tensor},
+    tensor::{DeviceStorage, Gradients, Tensor, UniqueId},
     tensor_ops::Device,
 };
 
@@ -116,6 +116,12 @@ pub struct Sgd<M, E: Dtype, D: DeviceStorage> {
     /// Hyperparameter configuration
     pub cfg: SgdConfig<E>,
 
+    /// If `true`, parameters whose gradient contains NaN or inf are not updated.
+    /// See [Sgd::num_nan_skipped()]. Defaults to `false`.
+    pub skip_nan: bool,
+    nan_skipped: usize,
+    frozen: BTreeSet<UniqueId>,
+
     velocity: Gradients<E, D>,
 
     marker: PhantomData<*const M>,
@@ -126,10 +132,38 @@ impl<M, E: Dtype, D: DeviceStorage> Sgd<M, E, D> {
     pub fn new(_model: &M, cfg: SgdConfig<E>) -> Self {
         Self {
             cfg,
+            skip_nan: false,
+            nan_skipped: 0,
+            frozen: Default::default(),
             velocity: Gradients::leaky(),
             marker: PhantomData,
         }
     }
+
+    /// The number of parameters that were not updated during the last update because
+    /// their gradient contained NaN or inf. Only counted if [Sgd::skip_nan] is `true`.
+    pub fn num_nan_skipped(&self) -> usize {
+        self.nan_skipped
+    }
+
+    /// Freezes every tensor in `module`, which can be any part of the model. Frozen
+    /// tensors are not updated, and are allowed to be missing from the gradients, so
+    /// they can be run without a tape to skip computing their gradients.
+    /// See [Sgd::unfreeze()].
+    pub fn freeze<N: TensorCollection<E, D>>(&mut self, module: &N)
+    where
+        D: Device<E>,
+    {
+        update_frozen(&mut self.frozen, module, true);
+    }
+
+    /// Undoes [Sgd::freeze()] for every tensor in `module`.
+    pub fn unfreeze<N: TensorCollection<E, D>>(&mut self, module: &N)
+    where
+        D: Device<E>,
+    {
+        update_frozen(&mut self.frozen, module, false);
+    }
 }
 
 pub trait SgdKernel<E: Dtype>: DeviceStorage {
@@ -155,12 +189,15 @@ impl<E: Dtype, D: Device<E>, M> TensorVisitor<E, D>
         opts: TensorOptions<S, E, D>,
         p: &mut Tensor<S, E, D>,
     ) -> Result<Option<Tensor<S, E, D>>, Self::Err> {
-        if !opts.do_gradient_update {
+        if !opts.do_gradient_update || self.0.frozen.contains(&p.id) {
             return Ok(None);
         }
         let g = self.1.get_ref_checked(p);
         match g {
             None => self.2.add(p),
+            Some(_) if self.0.skip_nan && has_non_finite(&self.1.get(p)) => {
+                self.0.nan_skipped += 1;
+            }
             Some(g) => {
                 let v = self.0.velocity.get_or_alloc_mut(p)?;
                 SgdKernel::update(
@@ -182,6 +219,7 @@ impl<M: TensorCollection<E, D>, D: Device<E>, E: Dtype> Optimizer<M, D, E> for S
         module: &mut M,
         gradients: &Gradients<E, D>,
     ) -> Result<(), OptimizerUpdateError<D>> {
+        self.nan_skipped = 0;
         let mut op = (self, gradients, Default::default());
         let result = M::iter_tensors(&mut RecursiveWalker {
             m: module,
@@ -197,6 +235,7 @@ impl<M: TensorCollection<E, D>, D: Device<E>, E: Dtype> Optimizer<M, D, E> for S
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::nn::{builders, DeviceBuildExt, Module};
     use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     #[test]
@@ -222,6 +261,55 @@ mod tests {
         assert_close(&targ.array(), &[1.0; 5]);
     }
 
+    #[test]
+    fn test_sgd_skip_nan() {
+        let dev: TestDevice = Default::default();
+        let mut model = dev.build_module::<builders::Linear<2, 2>, TestDtype>();
+        let mut sgd = Sgd::new(&model, Default::default());
+        sgd.skip_nan = true;
+        let weight = model.weight.array();
+        let bias = model.bias.array();
+
+        // the weight gradient depends on x, the bias gradient doesn't
+        let x: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([1.0, TestDtype::NAN]);
+        let g = model.forward(x.leaky_trace()).sum().backward();
+        sgd.update(&mut model, &g).expect("");
+        assert_eq!(sgd.num_nan_skipped(), 1);
+        assert_eq!(model.weight.array(), weight);
+        assert_close(&model.bias.array(), &[bias[0] - 1e-2, bias[1] - 1e-2]);
+    }
+
+    #[test]
+    fn test_sgd_freeze() {
+        let dev: TestDevice = Default::default();
+        type Model = (builders::Linear<2, 3>, builders::Linear<3, 2>);
+        let mut model = dev.build_module::<Model, TestDtype>();
+        let mut sgd = Sgd::new(&model, Default::default());
+        sgd.freeze(&model.0);
+        let w0 = model.0.weight.array();
+        let w1 = model.1.weight.array();
+
+        let x: Tensor<Rank1<2>, TestDtype, _> = dev.sample_normal();
+        let g = model.forward(x.leaky_trace()).exp().sum().backward();
+        sgd.update(&mut model, &g).expect("");
+        assert_eq!(model.0.weight.array(), w0);
+        assert_ne!(model.1.weight.array(), w1);
+
+        // frozen layers can be run without a tape, so they have no gradients
+        let h = model.0.forward(x.clone());
+        let g = model.1.forward(h.leaky_trace()).exp().sum().backward();
+        sgd.update(&mut model, &g).expect("");
+        assert_eq!(model.0.weight.array(), w0);
+
+        sgd.unfreeze(&model.0);
+        let g = model.forward(x.leaky_trace()).exp().sum().backward();
+        sgd.update(&mut model, &g).expect("");
+        assert_ne!(model.0.weight.array(), w0);
+
+        let g = model.1.forward(h.leaky_trace()).exp().sum().backward();
+        assert!(sgd.update(&mut model, &g).is_err());
+    }
+
     #[test]
     fn test_sgd_no_momentum() {
         let dev: TestDevice = Default::default();
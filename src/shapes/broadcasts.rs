@@ -8,13 +8,24 @@ pub trait BroadcastShapeTo<S, Ax>: Sized {}
 
 /// Marker for shapes that can have their [Axes] `Ax` reduced. See Self::Reduced
 /// for the resulting type.
+///
+/// Self::KeepDim is the same shape with each dimension in `Ax` replaced with
+/// `Const<1>` (or `usize` for array shapes), see [ReduceKeepDimTo].
 pub trait ReduceShape<Ax>: Sized + HasAxes<Ax> + ReduceShapeTo<Self::Reduced, Ax> {
     type Reduced: Shape + BroadcastShapeTo<Self, Ax>;
+    type KeepDim: Shape + ReduceShapeTo<Self::Reduced, Ax>;
 }
 
+/// The shape of `S` after reducing `Ax` with the reduced dimensions kept.
+pub type KeepDim<S, Ax> = <S as ReduceShape<Ax>>::KeepDim;
+
+/// A dimension that has been reduced, but kept around.
+type C1 = Const<1>;
+
 impl ReduceShapeTo<(), Axis<0>> for () {}
 impl ReduceShape<Axis<0>> for () {
     type Reduced = ();
+    type KeepDim = ();
 }
 impl<Src: Shape, Dst: Shape + ReduceShapeTo<Src, Ax>, Ax> BroadcastShapeTo<Dst, Ax> for Src {}
 
@@ -23,23 +34,26 @@ macro_rules! broadcast_to_array {
         impl ReduceShapeTo<(), $Axes> for [usize; $DstNum] {}
         impl ReduceShape<$Axes> for [usize; $DstNum] {
             type Reduced = ();
+            type KeepDim = [usize; $DstNum];
         }
     };
     ($SrcNum:tt, ($($SrcDims:tt),*), $DstNum:tt, ($($DstDims:tt),*), $Axes:ty) => {
         impl ReduceShapeTo<[usize; $SrcNum], $Axes> for [usize; $DstNum] {}
         impl ReduceShape<$Axes> for [usize; $DstNum] {
             type Reduced = [usize; $SrcNum];
+            type KeepDim = [usize; $DstNum];
         }
     };
 }
 
 macro_rules! broadcast_to {
-    ($SrcNum:tt, ($($SrcDims:tt),*), $DstNum:tt, ($($DstDims:tt),*), ()<>) => {
+    ($SrcNum:tt, ($($SrcDims:tt),*), $DstNum:tt, ($($DstDims:tt),*), ($($KeepDims:tt),*), ()<>) => {
     };
-    ($SrcNum:tt, ($($SrcDims:tt),*), $DstNum:tt, ($($DstDims:tt),*), $Axes:ty) => {
+    ($SrcNum:tt, ($($SrcDims:tt),*), $DstNum:tt, ($($DstDims:tt),*), ($($KeepDims:tt),*), $Axes:ty) => {
         impl<$($DstDims: Dim, )*> ReduceShapeTo<($($SrcDims, )*), $Axes> for ($($DstDims, )*) {}
         impl<$($DstDims: Dim, )*> ReduceShape<$Axes> for ($($DstDims, )*) {
             type Reduced = ($($SrcDims, )*);
+            type KeepDim = ($($KeepDims, )*);
         }
         broadcast_to_array!($SrcNum, ($($SrcDims),*), $DstNum, ($($DstDims),*), $Axes);
     };
@@ -54,27 +68,28 @@ pub(crate) use length;
 
 // Defines all reduce/broadcast rules recursively
 macro_rules! broadcast_to_all {
-    ([$($s1:ident)*] [$($s2:ident)*] [$($ax:tt)*] [] [$axis:tt $($axes:tt)*]) => {
-        broadcast_to!({length!($($s1)*)}, ($($s1),*), {length!($($s2)*)}, ($($s2),*), $axis<$({$ax}),*>);
+    ([$($s1:ident)*] [$($s2:ident)*] [$($k:ident)*] [$($ax:tt)*] [] [$axis:tt $($axes:tt)*]) => {
+        broadcast_to!({length!($($s1)*)}, ($($s1),*), {length!($($s2)*)}, ($($s2),*), ($($k),*), $axis<$({$ax}),*>);
     };
     (
         [$($s1:ident)*]
         [$($s2:ident)*]
+        [$($k:ident)*]
         [$($ax:tt)*]
         [$sh:ident $($shs:ident)*]
         [$axis:tt $($axes:tt)*]
     ) => {
-        broadcast_to!({length!($($s1)*)}, ($($s1),*), {length!($($s2)*)}, ($($s2),*), $axis<$({$ax}),*>);
+        broadcast_to!({length!($($s1)*)}, ($($s1),*), {length!($($s2)*)}, ($($s2),*), ($($k),*), $axis<$({$ax}),*>);
 
-        // Add a broadcasted dimension to the end of s2
-        broadcast_to_all!([$($s1)*] [$($s2)* $sh] [$($ax)* {length!($($s2)*)}] [$($shs)*] [$($axes)*]);
+        // Add a broadcasted dimension to the end of s2 (and a kept dimension to k)
+        broadcast_to_all!([$($s1)*] [$($s2)* $sh] [$($k)* C1] [$($ax)* {length!($($s2)*)}] [$($shs)*] [$($axes)*]);
 
-        // Add a dimension to both s1 and s2
-        broadcast_to_all!([$($s1)* $sh] [$($s2)* $sh] [$($ax)*] [$($shs)*] [$axis $($axes)*]);
+        // Add a dimension to s1, s2, and k
+        broadcast_to_all!([$($s1)* $sh] [$($s2)* $sh] [$($k)* $sh] [$($ax)*] [$($shs)*] [$axis $($axes)*]);
     }
 }
 
-broadcast_to_all!([] [] [] [A B C D E F] [() Axis Axes2 Axes3 Axes4 Axes5 Axes6]);
+broadcast_to_all!([] [] [] [] [A B C D E F] [() Axis Axes2 Axes3 Axes4 Axes5 Axes6]);
 
 /// Internal implementation for broadcasting strides
 pub trait BroadcastStridesTo<S: Shape, Ax>: Shape + BroadcastShapeTo<S, Ax> {
@@ -137,6 +152,29 @@ where
     }
 }
 
+/// Internal implementation for reducing a shape while keeping the reduced dimensions
+pub trait ReduceKeepDimTo<Ax>: Shape + ReduceShape<Ax> {
+    /// Replaces each dimension in `Ax` with `1`.
+    fn keep_dim(&self) -> <Self as ReduceShape<Ax>>::KeepDim;
+}
+
+impl<S: Shape + ReduceShape<Ax>, Ax: Axes> ReduceKeepDimTo<Ax> for S {
+    #[inline(always)]
+    fn keep_dim(&self) -> <Self as ReduceShape<Ax>>::KeepDim {
+        let src_dims = self.concrete();
+        let mut dst_dims: <<Self as ReduceShape<Ax>>::KeepDim as Shape>::Concrete =
+            Default::default();
+        for i in 0..Self::NUM_DIMS {
+            dst_dims[i] = if Ax::as_array().into_iter().any(|x| x == i as isize) {
+                1
+            } else {
+                src_dims[i]
+            };
+        }
+        <Self as ReduceShape<Ax>>::KeepDim::from_concrete(&dst_dims).unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,54 @@
+use super::{axes::*, shape::*};
+
+/// Marker for shapes whose dimensions from axis `Ax` onwards can be flattened
+/// into a single dimension. The flattened dimension becomes a [usize].
+pub trait FlattenShape<Ax: Axes<Array = [isize; 1]>>: Shape {
+    type Flattened: Shape;
+
+    #[inline]
+    fn flattened(&self) -> Self::Flattened {
+        let ax = Ax::as_array()[0] as usize;
+        let dims = self.concrete();
+        let mut out: <Self::Flattened as Shape>::Concrete = Default::default();
+        for i in 0..ax {
+            out[i] = dims[i];
+        }
+        out[ax] = dims.as_ref()[ax..].iter().product();
+        Self::Flattened::from_concrete(&out).unwrap()
+    }
+}
+
+macro_rules! flatten {
+    (($($D:ident),*), $Ax:literal, ($($P:ty),*)) => {
+        impl<$($D: Dim),*> FlattenShape<Axis<$Ax>> for ($($D,)*) {
+            type Flattened = ($($P,)*);
+        }
+    };
+}
+
+flatten!((D0), 0, (usize));
+
+flatten!((D0, D1), 0, (usize));
+flatten!((D0, D1), 1, (D0, usize));
+
+flatten!((D0, D1, D2), 0, (usize));
+flatten!((D0, D1, D2), 1, (D0, usize));
+flatten!((D0, D1, D2), 2, (D0, D1, usize));
+
+flatten!((D0, D1, D2, D3), 0, (usize));
+flatten!((D0, D1, D2, D3), 1, (D0, usize));
+flatten!((D0, D1, D2, D3), 2, (D0, D1, usize));
+flatten!((D0, D1, D2, D3), 3, (D0, D1, D2, usize));
+
+flatten!((D0, D1, D2, D3, D4), 0, (usize));
+flatten!((D0, D1, D2, D3, D4), 1, (D0, usize));
+flatten!((D0, D1, D2, D3, D4), 2, (D0, D1, usize));
+flatten!((D0, D1, D2, D3, D4), 3, (D0, D1, D2, usize));
+flatten!((D0, D1, D2, D3, D4), 4, (D0, D1, D2, D3, usize));
+
+flatten!((D0, D1, D2, D3, D4, D5), 0, (usize));
+flatten!((D0, D1, D2, D3, D4, D5), 1, (D0, usize));
+flatten!((D0, D1, D2, D3, D4, D5), 2, (D0, D1, usize));
+flatten!((D0, D1, D2, D3, D4, D5), 3, (D0, D1, D2, usize));
+flatten!((D0, D1, D2, D3, D4, D5), 4, (D0, D1, D2, D3, usize));
+flatten!((D0, D1, D2, D3, D4, D5), 5, (D0, D1, D2, D3, D4, usize));
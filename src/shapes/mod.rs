@@ -11,20 +11,25 @@
 
 mod axes;
 mod broadcasts;
+mod flatten;
 mod permutes;
 mod realize;
 mod replace_dim;
+mod resize_dim;
 mod same_numel;
 mod shape;
 mod slice;
 
 pub(crate) use axes::Axes;
 pub(crate) use broadcasts::{
-    BroadcastShapeTo, BroadcastStridesTo, ReduceShape, ReduceShapeTo, ReduceStridesTo,
+    BroadcastShapeTo, BroadcastStridesTo, KeepDim, ReduceKeepDimTo, ReduceShape, ReduceShapeTo,
+    ReduceStridesTo,
 };
+pub(crate) use flatten::FlattenShape;
 pub(crate) use permutes::{PermuteShapeTo, PermuteStridesTo};
 pub(crate) use realize::RealizeShapeTo;
 pub(crate) use replace_dim::{RemoveDimTo, ReplaceDimTo};
+pub(crate) use resize_dim::ResizeDimShape;
 
 pub(crate) use same_numel::AssertSameNumel;
 pub(crate) use slice::SliceShape;
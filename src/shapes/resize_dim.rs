@@ -0,0 +1,50 @@
+use super::{axes::*, shape::*};
+
+/// Marker for shapes that can have the size of axis `Ax` changed. The resized
+/// dimension becomes a [usize], since its size is only known at runtime.
+pub trait ResizeDimShape<Ax: Axes<Array = [isize; 1]>>: Shape {
+    type Resized: Shape<Concrete = Self::Concrete>;
+
+    #[inline]
+    fn resize_dim(&self, size: usize) -> Self::Resized {
+        let ax = Ax::as_array()[0] as usize;
+        let mut dims = self.concrete();
+        dims[ax] = size;
+        Self::Resized::from_concrete(&dims).unwrap()
+    }
+}
+
+macro_rules! resize_dim {
+    (($($D:ident),*), $Ax:literal, ($($P:ty),*)) => {
+        impl<$($D: Dim),*> ResizeDimShape<Axis<$Ax>> for ($($D,)*) {
+            type Resized = ($($P,)*);
+        }
+    };
+}
+
+resize_dim!((D0), 0, (usize));
+
+resize_dim!((D0, D1), 0, (usize, D1));
+resize_dim!((D0, D1), 1, (D0, usize));
+
+resize_dim!((D0, D1, D2), 0, (usize, D1, D2));
+resize_dim!((D0, D1, D2), 1, (D0, usize, D2));
+resize_dim!((D0, D1, D2), 2, (D0, D1, usize));
+
+resize_dim!((D0, D1, D2, D3), 0, (usize, D1, D2, D3));
+resize_dim!((D0, D1, D2, D3), 1, (D0, usize, D2, D3));
+resize_dim!((D0, D1, D2, D3), 2, (D0, D1, usize, D3));
+resize_dim!((D0, D1, D2, D3), 3, (D0, D1, D2, usize));
+
+resize_dim!((D0, D1, D2, D3, D4), 0, (usize, D1, D2, D3, D4));
+resize_dim!((D0, D1, D2, D3, D4), 1, (D0, usize, D2, D3, D4));
+resize_dim!((D0, D1, D2, D3, D4), 2, (D0, D1, usize, D3, D4));
+resize_dim!((D0, D1, D2, D3, D4), 3, (D0, D1, D2, usize, D4));
+resize_dim!((D0, D1, D2, D3, D4), 4, (D0, D1, D2, D3, usize));
+
+resize_dim!((D0, D1, D2, D3, D4, D5), 0, (usize, D1, D2, D3, D4, D5));
+resize_dim!((D0, D1, D2, D3, D4, D5), 1, (D0, usize, D2, D3, D4, D5));
+resize_dim!((D0, D1, D2, D3, D4, D5), 2, (D0, D1, usize, D3, D4, D5));
+resize_dim!((D0, D1, D2, D3, D4, D5), 3, (D0, D1, D2, usize, D4, D5));
+resize_dim!((D0, D1, D2, D3, D4, D5), 4, (D0, D1, D2, D3, usize, D5));
+resize_dim!((D0, D1, D2, D3, D4, D5), 5, (D0, D1, D2, D3, D4, usize));
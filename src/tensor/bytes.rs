@@ -0,0 +1,131 @@
+use super::{Cpu, CpuError, DeviceStorage, Tensor, TensorFromVec};
+use crate::shapes::{Shape, Unit};
+
+use std::vec::Vec;
+
+/// Dtypes that can be converted to and from little-endian bytes.
+pub trait LeBytes: Unit {
+    /// The number of bytes a single value takes up.
+    const NUM_BYTES: usize;
+
+    fn extend_le_bytes(self, bytes: &mut Vec<u8>);
+
+    /// Reads a value from the first [LeBytes::NUM_BYTES] bytes of `bytes`.
+    fn read_le_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! le_bytes {
+    ($Ty:ty, $NumBytes:expr) => {
+        impl LeBytes for $Ty {
+            const NUM_BYTES: usize = $NumBytes;
+            fn extend_le_bytes(self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+            fn read_le_bytes(bytes: &[u8]) -> Self {
+                Self::from_le_bytes(bytes[..$NumBytes].try_into().unwrap())
+            }
+        }
+    };
+}
+
+le_bytes!(f32, 4);
+le_bytes!(f64, 8);
+
+impl<S: Shape, E: LeBytes, D: DeviceStorage, T> Tensor<S, E, D, T> {
+    /// Returns the elements of the tensor as little-endian bytes, in
+    /// row major order.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([1.0f32, -2.0]);
+    /// let bytes = t.as_bytes();
+    /// assert_eq!(bytes.len(), 8);
+    /// assert_eq!(bytes[..4], 1.0f32.to_le_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let data = self.as_vec();
+        let mut bytes = Vec::with_capacity(data.len() * E::NUM_BYTES);
+        for x in data {
+            x.extend_le_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
+
+impl Cpu {
+    /// Creates a tensor from little-endian bytes, such as those returned by [Tensor::as_bytes].
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let bytes: Vec<u8> = [1.0f32, 2.0, 3.0].iter().flat_map(|x| x.to_le_bytes()).collect();
+    /// let t: Tensor<Rank1<3>, f32, _> = dev.tensor_from_bytes(&bytes, Default::default());
+    /// assert_eq!(t.array(), [1.0, 2.0, 3.0]);
+    /// ```
+    pub fn tensor_from_bytes<S: Shape, E: LeBytes>(
+        &self,
+        bytes: &[u8],
+        shape: S,
+    ) -> Tensor<S, E, Self> {
+        self.try_tensor_from_bytes(bytes, shape).unwrap()
+    }
+
+    /// Fallible version of [Cpu::tensor_from_bytes]. Returns [CpuError::WrongNumElements]
+    /// if the length of `bytes` doesn't match `shape`.
+    pub fn try_tensor_from_bytes<S: Shape, E: LeBytes>(
+        &self,
+        bytes: &[u8],
+        shape: S,
+    ) -> Result<Tensor<S, E, Self>, CpuError> {
+        if bytes.len() != shape.num_elements() * E::NUM_BYTES {
+            return Err(CpuError::WrongNumElements {
+                expected: shape.num_elements(),
+                actual: bytes.len() / E::NUM_BYTES,
+            });
+        }
+        let data = bytes
+            .chunks_exact(E::NUM_BYTES)
+            .map(E::read_le_bytes)
+            .collect();
+        self.try_tensor_from_vec(data, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tests::*};
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let bytes = t.as_bytes();
+        assert_eq!(bytes.len(), 6 * std::mem::size_of::<TestDtype>());
+        let r: Tensor<Rank2<2, 3>, TestDtype, _> =
+            dev.tensor_from_bytes(&bytes, Default::default());
+        assert_eq!(r.array(), t.array());
+    }
+
+    #[test]
+    fn test_bytes_wrong_len() {
+        let dev: Cpu = Default::default();
+        let bytes = dev.tensor([1.0f32, 2.0, 3.0]).as_bytes();
+        let r = dev.try_tensor_from_bytes::<Rank1<3>, f32>(&bytes[1..], Default::default());
+        assert!(matches!(
+            r,
+            Err(CpuError::WrongNumElements {
+                expected: 3,
+                actual: 2
+            })
+        ));
+        let r = dev.try_tensor_from_bytes::<Rank1<2>, f32>(&bytes, Default::default());
+        assert!(matches!(
+            r,
+            Err(CpuError::WrongNumElements {
+                expected: 2,
+                actual: 3
+            })
+        ));
+    }
+}
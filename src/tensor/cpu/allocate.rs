@@ -78,6 +78,27 @@ impl<E: Unit> OnesTensor<E> for Cpu {
     }
 }
 
+impl<E: Unit> FullTensor<E> for Cpu {
+    fn try_full_like<S: HasShape>(
+        &self,
+        src: &S,
+        value: E,
+    ) -> Result<Tensor<S::Shape, E, Self>, Self::Err> {
+        let shape = *src.shape();
+        let strides = shape.strides();
+        let data = self.try_alloc_elem::<E>(shape.num_elements(), value)?;
+        let data = Arc::new(data);
+        Ok(Tensor {
+            id: unique_id(),
+            data,
+            shape,
+            strides,
+            device: self.clone(),
+            tape: Default::default(),
+        })
+    }
+}
+
 impl<E: Unit> TriangleTensor<E> for Cpu {
     fn try_upper_tri_like<S: HasShape>(
         &self,
@@ -185,7 +206,10 @@ impl<E: Unit> TensorFromVec<E> for Cpu {
         let num_elements = shape.num_elements();
 
         if src.len() != num_elements {
-            Err(CpuError::WrongNumElements)
+            Err(CpuError::WrongNumElements {
+                expected: num_elements,
+                actual: src.len(),
+            })
         } else {
             Ok(Tensor {
                 id: unique_id(),
@@ -14,15 +14,25 @@ use std::sync::Mutex;
 /// The [Default] impl seeds the underlying rng with seed of 0.
 ///
 /// Use [Cpu::seed_from_u64] to control what seed is used.
+///
+/// Every random op (e.g. [crate::tensor::SampleTensor::sample_normal()]) advances the rng,
+/// so consecutive calls give different values, while a device created with the same
+/// seed produces the same sequence. Clones of a device share the same rng.
 #[derive(Clone, Debug)]
 pub struct Cpu {
     pub(crate) rng: Arc<Mutex<StdRng>>,
+    /// Cumulative kernel timings, see [Cpu::take_timings].
+    #[cfg(feature = "timings")]
+    pub(crate) timings:
+        Arc<Mutex<std::collections::HashMap<std::string::String, super::KernelTiming>>>,
 }
 
 impl Default for Cpu {
     fn default() -> Self {
         Self {
             rng: Arc::new(Mutex::new(StdRng::seed_from_u64(0))),
+            #[cfg(feature = "timings")]
+            timings: Default::default(),
         }
     }
 }
@@ -32,6 +42,8 @@ impl Cpu {
     pub fn seed_from_u64(seed: u64) -> Self {
         Self {
             rng: Arc::new(Mutex::new(StdRng::seed_from_u64(seed))),
+            #[cfg(feature = "timings")]
+            timings: Default::default(),
         }
     }
 }
@@ -40,15 +52,18 @@ impl Cpu {
 pub enum CpuError {
     /// Device is out of memory
     OutOfMemory,
-    /// Not enough elements were provided when creating a tensor
-    WrongNumElements,
+    /// The wrong number of elements were provided when creating a tensor
+    WrongNumElements { expected: usize, actual: usize },
 }
 
 impl std::fmt::Display for CpuError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::OutOfMemory => f.write_str("CpuError::OutOfMemory"),
-            Self::WrongNumElements => f.write_str("CpuError::WrongNumElements"),
+            Self::WrongNumElements { expected, actual } => write!(
+                f,
+                "CpuError::WrongNumElements: expected {expected} elements, found {actual}"
+            ),
         }
     }
 }
@@ -82,6 +97,10 @@ impl DeviceStorage for Cpu {
         v.len()
     }
 
+    fn storage_to_vec<E: Unit>(&self, v: &Self::Vec<E>) -> Vec<E> {
+        v.clone()
+    }
+
     fn tensor_to_vec<S: Shape, E: Unit, T>(&self, tensor: &Tensor<S, E, Self, T>) -> Vec<E> {
         let mut buf = Vec::with_capacity(tensor.shape.num_elements());
         let mut iter = tensor.iter();
@@ -94,4 +113,9 @@ impl DeviceStorage for Cpu {
     fn try_synchronize(&self) -> Result<(), Self::Err> {
         Ok(())
     }
+
+    fn supports_dtype<E: Unit>() -> bool {
+        let id = core::any::TypeId::of::<E>();
+        id == core::any::TypeId::of::<f32>() || id == core::any::TypeId::of::<f64>()
+    }
 }
@@ -35,3 +35,79 @@ impl<S: Shape, E: Unit, T> std::ops::IndexMut<S::Concrete> for Tensor<S, E, Cpu,
         &mut data[i]
     }
 }
+
+impl<S: Shape, E: Unit, T> Tensor<S, E, Cpu, T> {
+    /// Whether the underlying storage is exactly the tensor's elements in row major order.
+    fn is_contiguous(&self) -> bool {
+        self.strides == self.shape.strides() && self.data.len() == self.shape.num_elements()
+    }
+
+    /// Borrows the elements of the tensor in row major order without copying.
+    /// Returns `None` if the tensor is not contiguous, for example after a permute
+    /// or broadcast. See [Tensor::as_vec()] for a version that always works.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// assert_eq!(t.try_as_slice(), Some([1.0, 2.0, 3.0, 4.0].as_slice()));
+    /// assert_eq!(t.permute::<Rank2<2, 2>, _>().try_as_slice(), None);
+    /// ```
+    pub fn try_as_slice(&self) -> Option<&[E]> {
+        self.is_contiguous().then(|| self.data.as_slice())
+    }
+
+    /// Mutably borrows the elements of the tensor in row major order without copying.
+    /// Returns `None` if the tensor is not contiguous, or if its storage is shared with
+    /// another tensor (e.g. a clone, or a tensor kept alive by a tape), since writing to it
+    /// would modify the other tensor too.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let mut t = dev.tensor([1.0, 2.0, 3.0]);
+    /// t.try_as_mut_slice().unwrap().copy_from_slice(&[4.0, 5.0, 6.0]);
+    /// assert_eq!(t.array(), [4.0, 5.0, 6.0]);
+    /// ```
+    pub fn try_as_mut_slice(&mut self) -> Option<&mut [E]> {
+        if self.is_contiguous() {
+            Arc::get_mut(&mut self.data).map(|data| data.as_mut_slice())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_try_as_slice() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        assert_eq!(t.try_as_slice(), Some(t.as_vec().as_slice()));
+        assert_eq!(t.clone().permute::<Rank2<3, 2>, _>().try_as_slice(), None);
+
+        let b: Tensor<Rank2<4, 3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]).broadcast();
+        assert_eq!(b.try_as_slice(), None);
+    }
+
+    #[test]
+    fn test_try_as_mut_slice() {
+        let dev: Cpu = Default::default();
+        let mut t: Tensor<Rank2<2, 2>, f32, _> = dev.zeros();
+        t.try_as_mut_slice()
+            .unwrap()
+            .copy_from_slice(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(t.array(), [[1.0, 2.0], [3.0, 4.0]]);
+
+        let shared = t.clone();
+        assert!(t.try_as_mut_slice().is_none());
+        drop(shared);
+        assert!(t.try_as_mut_slice().is_some());
+
+        let mut p = t.permute::<Rank2<2, 2>, _>();
+        assert!(p.try_as_mut_slice().is_none());
+    }
+}
@@ -126,6 +126,13 @@ impl<S: Shape, E: Unit, T> Tensor<S, E, Cpu, T> {
         std::sync::Arc::make_mut(&mut self.data).iter_mut()
     }
 
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub(crate) fn buf_par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, E> {
+        use rayon::prelude::*;
+        std::sync::Arc::make_mut(&mut self.data).par_iter_mut()
+    }
+
     #[inline]
     pub(crate) fn iter(&self) -> StridedRefIter<S, E> {
         StridedRefIter {
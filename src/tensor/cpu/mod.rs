@@ -2,8 +2,14 @@ mod allocate;
 mod device;
 mod index;
 mod iterate;
+#[cfg(feature = "timings")]
+mod timings;
 
 pub(crate) use index::index_to_i;
 pub(crate) use iterate::{LendingIterator, NdIndex};
 
 pub use device::{Cpu, CpuError};
+#[cfg(feature = "timings")]
+pub(crate) use timings::kernel_name;
+#[cfg(feature = "timings")]
+pub use timings::KernelTiming;
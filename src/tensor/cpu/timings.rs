@@ -0,0 +1,108 @@
+use super::Cpu;
+
+use std::{
+    collections::HashMap,
+    string::{String, ToString},
+    time::{Duration, Instant},
+};
+
+/// Cumulative time spent in a single kind of kernel. This is wall-clock time
+/// on the Cpu, and the time between CUDA events recorded around the launch on Cuda.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KernelTiming {
+    /// Number of times the kernel was invoked.
+    pub calls: usize,
+    /// Total time spent across all invocations.
+    pub total: Duration,
+}
+
+/// The short type name of `Op`, e.g. `"ExpKernelOp"`.
+pub(crate) fn kernel_name<Op>() -> &'static str {
+    let name = std::any::type_name::<Op>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Records the time between construction and drop into the [Cpu]'s timings.
+pub(crate) struct KernelTimer<'a> {
+    dev: &'a Cpu,
+    name: &'static str,
+    start: Instant,
+}
+
+impl Drop for KernelTimer<'_> {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        #[cfg(not(feature = "no-std"))]
+        let mut timings = self.dev.timings.lock().unwrap();
+        #[cfg(feature = "no-std")]
+        let mut timings = self.dev.timings.lock();
+        let timing = timings.entry(self.name.to_string()).or_default();
+        timing.calls += 1;
+        timing.total += elapsed;
+    }
+}
+
+impl Cpu {
+    /// Starts timing a kernel, which is named after the short type name of `Op`.
+    /// The time is recorded when the returned timer is dropped.
+    pub(crate) fn time_kernel<Op>(&self) -> KernelTimer<'_> {
+        KernelTimer {
+            dev: self,
+            name: kernel_name::<Op>(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the cumulative timings of each kernel run since the last call,
+    /// keyed by the name of the kernel op (e.g. `"ExpKernelOp"`), and resets them.
+    ///
+    /// Currently only elementwise unary and binary kernels are timed.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank1<3>, f32, _> = dev.zeros();
+    /// let _ = t.exp();
+    /// let timings = dev.take_timings();
+    /// assert_eq!(timings["ExpKernelOp"].calls, 1);
+    /// ```
+    pub fn take_timings(&self) -> HashMap<String, KernelTiming> {
+        #[cfg(not(feature = "no-std"))]
+        let mut timings = self.timings.lock().unwrap();
+        #[cfg(feature = "no-std")]
+        let mut timings = self.timings.lock();
+        std::mem::take(&mut *timings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*};
+
+    #[test]
+    fn test_timings_count_invocations() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<1000>, f32, _> = dev.sample_normal();
+        let _ = dev.take_timings();
+
+        let _ = t.clone().exp();
+        let _ = t.clone().exp();
+        let _ = t.clone() + t.clone();
+
+        let timings = dev.take_timings();
+        assert_eq!(timings["ExpKernelOp"].calls, 2);
+        assert!(timings["ExpKernelOp"].total > std::time::Duration::ZERO);
+        assert_eq!(timings["BinaryAddKernelOp"].calls, 1);
+
+        assert!(dev.take_timings().is_empty());
+    }
+
+    #[test]
+    fn test_timings_shared_between_clones() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.clone().zeros();
+        let _ = t.exp();
+        assert_eq!(dev.take_timings()["ExpKernelOp"].calls, 1);
+    }
+}
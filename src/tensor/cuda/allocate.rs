@@ -41,7 +41,7 @@ impl<E: Unit> ZerosTensor<E> for Cuda {
     fn try_zeros_like<S: HasShape>(&self, src: &S) -> Result<Tensor<S::Shape, E, Self>, Self::Err> {
         let shape = *src.shape();
         let strides = shape.strides();
-        let data = self.dev.alloc_zeros(shape.num_elements())?;
+        let data = self.try_alloc_len(shape.num_elements())?;
         Ok(self.build_tensor(shape, strides, data))
     }
 }
@@ -64,6 +64,21 @@ where
     }
 }
 
+impl<E: Unit> FullTensor<E> for Cuda
+where
+    Cpu: FullTensor<E>,
+{
+    fn try_full_like<S: HasShape>(
+        &self,
+        src: &S,
+        value: E,
+    ) -> Result<Tensor<S::Shape, E, Self>, Self::Err> {
+        let shape = *src.shape();
+        let buf = std::vec![value; shape.num_elements()];
+        self.tensor_from_host_buf(shape, buf)
+    }
+}
+
 impl<E: Unit> TriangleTensor<E> for Cuda
 where
     Cpu: TriangleTensor<E>,
@@ -169,7 +184,10 @@ impl<E: Unit> TensorFromVec<E> for Cuda {
         let num_elements = shape.num_elements();
 
         if src.len() != num_elements {
-            Err(CudaError::Cpu(CpuError::WrongNumElements))
+            Err(CudaError::Cpu(CpuError::WrongNumElements {
+                expected: num_elements,
+                actual: src.len(),
+            }))
         } else {
             self.tensor_from_host_buf(shape, src)
         }
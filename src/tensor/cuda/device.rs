@@ -17,6 +17,10 @@ use std::{
 /// & running GPU kernels.
 #[derive(Clone, Debug)]
 pub struct Cuda {
+    /// Kernel timings that haven't been read yet, see [Cuda::take_timings]. Declared
+    /// before `dev`, so its events are destroyed before the device is dropped.
+    #[cfg(feature = "timings")]
+    pub(crate) timings: Arc<Mutex<Vec<super::timings::PendingTiming>>>,
     pub(crate) cpu: Cpu,
     pub(crate) dev: Arc<CudaDevice>,
     pub(crate) blas: Arc<CudaBlas>,
@@ -35,6 +39,28 @@ pub enum CudaError {
     Cudnn(cudarc::cudnn::CudnnError),
     Driver(DriverError),
     Cpu(CpuError),
+    /// The device ran out of memory while allocating `num_bytes` for `op`.
+    OutOfMemory {
+        op: &'static str,
+        num_bytes: usize,
+        source: DriverError,
+    },
+}
+
+impl CudaError {
+    /// Turns a failed allocation of `num_bytes` by `op` into [CudaError::OutOfMemory] if
+    /// the driver reported out of memory, and [CudaError::Driver] otherwise.
+    pub(crate) fn from_alloc(op: &'static str, err: DriverError, num_bytes: usize) -> Self {
+        if err.0 == cudarc::driver::sys::CUresult::CUDA_ERROR_OUT_OF_MEMORY {
+            Self::OutOfMemory {
+                op,
+                num_bytes,
+                source: err,
+            }
+        } else {
+            Self::Driver(err)
+        }
+    }
 }
 
 impl From<CpuError> for CudaError {
@@ -89,6 +115,8 @@ impl Cuda {
         let par_stream = Arc::new(dev.fork_default_stream()?);
         let workspace = Arc::new(Mutex::new(dev.alloc_zeros::<u8>(0)?));
         Ok(Self {
+            #[cfg(feature = "timings")]
+            timings: Default::default(),
             cpu,
             dev,
             blas,
@@ -112,7 +140,8 @@ impl Cuda {
         // re-allocate a larger workspace
         if workspace.num_bytes() < num_bytes_required {
             // we are about to memset this to zero, so this is still okay
-            *workspace = unsafe { self.dev.alloc::<u8>(num_bytes_required) }?;
+            *workspace = unsafe { self.dev.alloc::<u8>(num_bytes_required) }
+                .map_err(|e| CudaError::from_alloc("get_workspace", e, num_bytes_required))?;
         }
 
         Ok(workspace)
@@ -121,7 +150,33 @@ impl Cuda {
 
 impl std::fmt::Display for CudaError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            Self::OutOfMemory {
+                op,
+                num_bytes,
+                source,
+            } => {
+                write!(
+                    f,
+                    "out of memory allocating {num_bytes} bytes in {op}: {source:?}"
+                )
+            }
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CudaError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Blas(err) => Some(err),
+            #[cfg(feature = "cudnn")]
+            Self::Cudnn(err) => Some(err),
+            Self::Driver(err) => Some(err),
+            Self::Cpu(err) => Some(err),
+            Self::OutOfMemory { source, .. } => Some(source),
+        }
     }
 }
 
@@ -133,7 +188,9 @@ impl DeviceStorage for Cuda {
     type Vec<E: Unit> = CudaSlice<E>;
 
     fn try_alloc_len<E: Unit>(&self, len: usize) -> Result<Self::Vec<E>, Self::Err> {
-        Ok(self.dev.alloc_zeros(len)?)
+        self.dev
+            .alloc_zeros(len)
+            .map_err(|e| CudaError::from_alloc("try_alloc_len", e, len * std::mem::size_of::<E>()))
     }
 
     fn random_u64(&self) -> u64 {
@@ -144,6 +201,10 @@ impl DeviceStorage for Cuda {
         v.len()
     }
 
+    fn storage_to_vec<E: Unit>(&self, v: &Self::Vec<E>) -> Vec<E> {
+        self.dev.dtoh_sync_copy(v).unwrap()
+    }
+
     fn tensor_to_vec<S: Shape, E: Unit, T>(&self, tensor: &Tensor<S, E, Self, T>) -> Vec<E> {
         let buf: Vec<E> = tensor.data.try_clone().unwrap().try_into().unwrap();
         debug_assert_eq!(buf.len(), tensor.data.len());
@@ -158,4 +219,9 @@ impl DeviceStorage for Cuda {
     fn try_synchronize(&self) -> Result<(), CudaError> {
         self.dev.synchronize().map_err(CudaError::from)
     }
+
+    fn supports_dtype<E: Unit>() -> bool {
+        let id = core::any::TypeId::of::<E>();
+        id == core::any::TypeId::of::<f32>() || id == core::any::TypeId::of::<f64>()
+    }
 }
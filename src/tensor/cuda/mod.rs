@@ -1,5 +1,7 @@
 mod allocate;
 mod device;
+#[cfg(feature = "timings")]
+mod timings;
 
 pub use device::{Cuda, CudaError};
 
@@ -0,0 +1,111 @@
+use super::{Cuda, CudaError};
+use crate::tensor::cpu::{kernel_name, KernelTiming};
+
+use cudarc::driver::{result::event, sys};
+
+use std::{
+    collections::HashMap,
+    string::{String, ToString},
+    time::Duration,
+};
+
+/// A pair of events recorded around a kernel launch, whose elapsed time
+/// hasn't been read yet. The events are destroyed on drop.
+#[derive(Debug)]
+pub(crate) struct PendingTiming {
+    name: &'static str,
+    start: sys::CUevent,
+    end: sys::CUevent,
+}
+
+// SAFETY: events are handles owned by the driver, and aren't tied to the thread that
+// created them.
+unsafe impl Send for PendingTiming {}
+
+impl Drop for PendingTiming {
+    fn drop(&mut self) {
+        unsafe {
+            event::destroy(self.start).ok();
+            event::destroy(self.end).ok();
+        }
+    }
+}
+
+/// Records an event when dropped, and adds the pair of events to the [Cuda]'s
+/// pending timings.
+pub(crate) struct KernelTimer<'a> {
+    dev: &'a Cuda,
+    timing: Option<PendingTiming>,
+}
+
+impl Drop for KernelTimer<'_> {
+    fn drop(&mut self) {
+        let timing = self.timing.take().unwrap();
+        if unsafe { event::record(timing.end, *self.dev.dev.cu_stream()) }.is_ok() {
+            self.dev.timings.lock().unwrap().push(timing);
+        }
+    }
+}
+
+impl Cuda {
+    /// Starts timing a kernel launched on the default stream, which is named after
+    /// the short type name of `Op`. The end of the kernel is recorded when the
+    /// returned timer is dropped.
+    pub(crate) fn time_kernel<Op>(&self) -> Result<KernelTimer<'_>, CudaError> {
+        self.dev.bind_to_thread()?;
+        let flags = sys::CUevent_flags::CU_EVENT_DEFAULT;
+        let timing = PendingTiming {
+            name: kernel_name::<Op>(),
+            start: event::create(flags)?,
+            end: event::create(flags)?,
+        };
+        unsafe { event::record(timing.start, *self.dev.cu_stream()) }?;
+        Ok(KernelTimer {
+            dev: self,
+            timing: Some(timing),
+        })
+    }
+
+    /// Returns the cumulative timings of each kernel run since the last call,
+    /// keyed by the name of the kernel op (e.g. `"ExpKernelOp"`), and resets them.
+    ///
+    /// Kernel times are measured with CUDA events, so this synchronizes the device
+    /// before reading them. Currently only elementwise unary and binary kernels are timed.
+    pub fn take_timings(&self) -> HashMap<String, KernelTiming> {
+        self.try_take_timings().unwrap()
+    }
+
+    /// Fallible version of [Cuda::take_timings]
+    pub fn try_take_timings(&self) -> Result<HashMap<String, KernelTiming>, CudaError> {
+        self.dev.synchronize()?;
+        let pending = std::mem::take(&mut *self.timings.lock().unwrap());
+        let mut timings: HashMap<String, KernelTiming> = HashMap::new();
+        for p in pending {
+            let ms = unsafe { event::elapsed(p.start, p.end) }?;
+            let timing = timings.entry(p.name.to_string()).or_default();
+            timing.calls += 1;
+            timing.total += Duration::from_secs_f32(ms / 1000.0);
+        }
+        Ok(timings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*};
+
+    #[test]
+    fn test_cuda_timings_count_invocations() {
+        let dev: Cuda = Default::default();
+        let t: Tensor<Rank1<1000>, f32, _> = dev.sample_normal();
+        let _ = dev.take_timings();
+
+        let _ = t.clone().exp();
+        let _ = t.clone().exp();
+
+        let timings = dev.take_timings();
+        assert_eq!(timings["ExpKernelOp"].calls, 2);
+        assert!(timings["ExpKernelOp"].total > std::time::Duration::ZERO);
+        assert!(dev.take_timings().is_empty());
+    }
+}
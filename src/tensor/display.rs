@@ -0,0 +1,128 @@
+use super::{DeviceStorage, Tensor};
+use crate::shapes::{Shape, Unit};
+
+use std::fmt::{Display, Formatter, Result};
+
+/// Axes longer than this are elided with `...`, keeping only the
+/// first and last `MAX_ITEMS / 2` elements.
+const MAX_ITEMS: usize = 6;
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn write_nested<E: Unit>(
+    f: &mut Formatter<'_>,
+    values: &[E],
+    dims: &[usize],
+    indent: usize,
+) -> Result {
+    if dims.is_empty() {
+        return write!(f, "{:?}", values[0]);
+    }
+
+    let n = dims[0];
+    let stride: usize = dims[1..].iter().product();
+    let elide = n > MAX_ITEMS;
+
+    let separator = |f: &mut Formatter<'_>| {
+        if dims.len() == 1 {
+            f.write_str(", ")
+        } else {
+            write!(f, ",\n{:indent$}", "", indent = indent + 1)
+        }
+    };
+
+    f.write_str("[")?;
+    for i in 0..n {
+        if elide && i >= MAX_ITEMS / 2 && i < n - MAX_ITEMS / 2 {
+            if i == MAX_ITEMS / 2 {
+                separator(f)?;
+                f.write_str("...")?;
+            }
+            continue;
+        }
+        if i > 0 {
+            separator(f)?;
+        }
+        write_nested(f, &values[i * stride..], &dims[1..], indent + 1)?;
+    }
+    f.write_str("]")
+}
+
+/// Prints the shape, dtype, and device of the tensor, followed by its values.
+/// Axes with more than 6 elements are elided with `...`.
+///
+/// The data is copied to the host before printing.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// assert_eq!(
+///     std::format!("{t}"),
+///     "Tensor(shape=[2, 3], dtype=f32, device=Cpu)\n[[1.0, 2.0, 3.0],\n [4.0, 5.0, 6.0]]"
+/// );
+/// ```
+impl<S: Shape, E: Unit, D: DeviceStorage, T> Display for Tensor<S, E, D, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let dims = self.shape.concrete();
+        writeln!(
+            f,
+            "Tensor(shape={:?}, dtype={}, device={})",
+            dims.as_ref(),
+            short_type_name::<E>(),
+            short_type_name::<D>(),
+        )?;
+        write_nested(f, &self.as_vec(), dims.as_ref(), 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_display_small() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(
+            std::format!("{t}"),
+            "Tensor(shape=[2, 3], dtype=f32, device=Cpu)\n[[1.0, 2.0, 3.0],\n [4.0, 5.0, 6.0]]"
+        );
+
+        let t: Tensor<Rank0, usize, _> = dev.tensor(3);
+        assert_eq!(
+            std::format!("{t}"),
+            "Tensor(shape=[], dtype=usize, device=Cpu)\n3"
+        );
+    }
+
+    #[test]
+    fn test_display_elides_large() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<100>, f32, _> =
+            dev.tensor_from_vec((0..100).map(|i| i as f32).collect(), (Const,));
+        assert_eq!(
+            std::format!("{t}"),
+            "Tensor(shape=[100], dtype=f32, device=Cpu)\n[0.0, 1.0, 2.0, ..., 97.0, 98.0, 99.0]"
+        );
+
+        let t: Tensor<Rank2<8, 2>, f32, _> = dev.zeros();
+        let s = std::format!("{t}");
+        assert_eq!(s.lines().count(), 8);
+        assert!(s.contains(",\n ...,\n "));
+    }
+
+    #[test]
+    fn test_display_permuted() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let t = t.permute::<_, Axes2<1, 0>>();
+        assert_eq!(
+            std::format!("{t}"),
+            "Tensor(shape=[2, 2], dtype=f32, device=Cpu)\n[[1.0, 3.0],\n [2.0, 4.0]]"
+        );
+    }
+}
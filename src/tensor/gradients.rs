@@ -9,7 +9,9 @@ use super::{
     storage_traits::{AllocGrad, DeviceStorage},
     unique_id, Tensor, UniqueId,
 };
-use crate::shapes::{Shape, Unit};
+use crate::shapes::{Dtype, Shape, Unit};
+
+use num_traits::Float;
 
 /// A generic container for keeping gradients of tensors keyed by the
 /// tensor's [UniqueId].
@@ -45,6 +47,46 @@ impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
     }
 }
 
+impl<E: Dtype + Float, D: DeviceStorage> Gradients<E, D> {
+    /// Returns the L2 norm of every gradient, keyed by the [UniqueId] of its tensor
+    /// (see [Tensor::id()]). This copies the gradients off of `device`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([1.0f32, 2.0]);
+    /// let mut g = (t.leaky_trace() * dev.tensor([3.0, 4.0])).sum().backward();
+    /// assert_eq!(g.norms(&dev)[t.id()], 5.0);
+    ///
+    /// // leaky gradients also hold the gradients of temporary tensors
+    /// g.retain_leafs(&[*t.id()]);
+    /// assert_eq!(g.global_norm(&dev), 5.0);
+    /// ```
+    pub fn norms(&self, device: &D) -> BTreeMap<UniqueId, E> {
+        self.gradient_by_id
+            .iter()
+            .map(|(id, g)| (*id, sum_squares(device, g).sqrt()))
+            .collect()
+    }
+
+    /// Returns the L2 norm of all gradients together, as if they were concatenated.
+    /// Gradients allocated with [crate::nn::ZeroGrads::alloc_grads()] only hold the
+    /// model's parameters after backward. This copies the gradients off of `device`.
+    pub fn global_norm(&self, device: &D) -> E {
+        self.gradient_by_id
+            .values()
+            .fold(E::zero(), |acc, g| acc + sum_squares(device, g))
+            .sqrt()
+    }
+}
+
+fn sum_squares<E: Dtype, D: DeviceStorage>(device: &D, g: &D::Vec<E>) -> E {
+    device
+        .storage_to_vec(g)
+        .into_iter()
+        .fold(E::default(), |acc, x| acc + x * x)
+}
+
 impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
     /// Retrieves mutable gradient for `t`, allocating one if it isn't present.
     pub(crate) fn get_or_alloc_mut<S: Shape>(
@@ -67,6 +109,19 @@ impl<E: Unit, D: DeviceStorage> Gradients<E, D> {
         Ok(())
     }
 
+    /// Sets the gradient of `dst` to a copy of the gradient of `src`, allocating
+    /// a gradient for `src` if it isn't present.
+    pub(crate) fn try_copy_for<S: Shape>(
+        &mut self,
+        src: &GhostTensor<S, E, D>,
+        dst: &GhostTensor<S, E, D>,
+    ) -> Result<(), D::Err> {
+        self.try_alloc_for(src)?;
+        let grad = self.get_ref(src).clone();
+        self.gradient_by_id.insert(dst.id, grad);
+        Ok(())
+    }
+
     /// Drops all gradients except for the ids specified in the parameter.
     pub fn retain_leafs(&mut self, ids: &[UniqueId]) {
         self.leaf_ids
@@ -214,14 +269,22 @@ impl<E: Unit, D: DeviceStorage> OwnedTape<E, D> {
     ///
     /// Note that this method takes ownership of self, so it can't be called twice!
     pub(crate) fn execute(mut self) -> Result<Gradients<E, D>, D::Err> {
+        let mut gradients = std::mem::replace(&mut self.gradients, Gradients::leaky());
+        self.execute_into(&mut gradients)?;
+        Ok(gradients)
+    }
+
+    /// Runs all the operations on `gradients` instead of the tape's own [Gradients],
+    /// which are dropped.
+    pub(crate) fn execute_into(mut self, gradients: &mut Gradients<E, D>) -> Result<(), D::Err> {
         // We must ensure that the operations are sorted in execution time order.
         // Otherwise an backward operation may not be executed in the right order
         // if multiple tapes were merged together.
         self.operations.sort_by_key(|(k, _)| *k);
         for (_, operation) in self.operations.drain(..).rev() {
-            (operation)(&mut self.gradients)?;
+            (operation)(gradients)?;
         }
-        Ok(self.gradients)
+        Ok(())
     }
 }
 
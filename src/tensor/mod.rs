@@ -40,6 +40,16 @@
 //! let _ = dev.tensor_from_vec(vec![1.0, 2.0, 3.0], (3, ));
 //! ```
 //!
+//! ### From a function of the index
+//!
+//! See [TensorFromFn].
+//!
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let _ = dev.from_fn::<Rank2<2, 3>>(|[i, j]| (i * j) as f32);
+//! ```
+//!
 //! ### Filled with 0s or 1s
 //!
 //! See [ZerosTensor] and [OnesTensor].
@@ -51,6 +61,16 @@
 //! let _: Tensor<Rank2<3, 2>, f32, _> = dev.ones();
 //! ```
 //!
+//! ### Filled with a value
+//!
+//! See [FullTensor]. For `0, 1, 2, ...` see [crate::data::Arange].
+//!
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let _: Tensor<Rank1<5>, f32, _> = dev.full(2.5);
+//! ```
+//!
 //! ### Filled with random data
 //!
 //! See [SampleTensor]
@@ -65,6 +85,8 @@
 //! let _: Tensor<Rank2<4, 3>, f32, _> = dev.sample(rand_distr::StandardNormal);
 //! ```
 //!
+//! For a seeded random permutation of indices, see [RandpermTensor].
+//!
 //! ### Copy data from slices
 //!
 //! You can use [Tensor::copy_from] and [Tensor::copy_into] to copy data into a tensor:
@@ -96,6 +118,18 @@
 //! let t: [[f32; 3]; 2] = t.array();
 //! ```
 //!
+//! # Printing tensors
+//!
+//! Tensors implement [std::fmt::Display], which prints the shape, dtype, and device
+//! followed by the (possibly elided) values:
+//!
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let t: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+//! std::println!("{t}");
+//! ```
+//!
 //! # Tracing gradients
 //!
 //! Use the [Tensor::trace] or [Tensor::traced] methods to add [OwnedTape] to the [Tensor].
@@ -112,6 +146,9 @@
 //! let t_clone: Tensor<Rank1<5>, f32, _, OwnedTape<f32, Cpu>> = t.trace(grads);
 //! ```
 //!
+//! Use [Tensor::no_grad] to drop the tape from a tensor, so that no further operations
+//! are recorded.
+//!
 //! ## Gradient Accumulation
 //!
 //! If you re-use the same gradients object without zero-ing out the gradients, you can
@@ -124,9 +161,11 @@
 //! You can also use [Tensor::write_to_npz] and [Tensor::read_from_npz] when working with
 //! zip archives.
 
+mod bytes;
 pub(crate) mod cpu;
 #[cfg(feature = "cuda")]
 pub(crate) mod cuda;
+mod display;
 mod ghost;
 mod gradients;
 mod masks;
@@ -142,7 +181,10 @@ mod tensor_impls;
 pub(crate) use ghost::GhostTensor;
 pub(crate) use storage_traits::{OneFillStorage, ZeroFillStorage};
 
+pub use bytes::LeBytes;
 pub use cpu::{Cpu, CpuError};
+#[cfg(feature = "timings")]
+pub use cpu::KernelTiming;
 #[cfg(not(feature = "cuda"))]
 pub type AutoDevice = Cpu;
 
@@ -153,9 +195,10 @@ pub use cuda::{Cuda, CudaError};
 #[cfg(feature = "cuda")]
 pub type AutoDevice = Cuda;
 
-pub use storage_traits::{AsArray, CopySlice, TensorFrom, TensorFromVec};
+pub use storage_traits::{AsArray, CopySlice, TensorFrom, TensorFromFn, TensorFromVec};
 pub use storage_traits::{DeviceStorage, HasErr};
-pub use storage_traits::{OnesTensor, SampleTensor, TriangleTensor, ZerosTensor};
+pub use storage_traits::{FullTensor, OnesTensor, RandpermTensor, SampleTensor};
+pub use storage_traits::{TriangleTensor, ZerosTensor};
 
 pub use tensor_impls::{PutTape, SplitTape, Tensor, Trace, WithEmptyTape};
 pub use tensor_impls::{Tensor0D, Tensor1D, Tensor2D, Tensor3D, Tensor4D, Tensor5D, Tensor6D};
@@ -168,8 +211,10 @@ pub use gradients::{Gradients, Merge, NoneTape, OwnedTape, Tape};
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::nn::{builders::*, DeviceBuildExt, Module, ZeroGrads};
     use crate::shapes::*;
-    use crate::tests::{TestDevice, TestDtype};
+    use crate::tensor_ops::*;
+    use crate::tests::{assert_close, TestDevice, TestDtype};
     use std::collections::HashSet;
 
     #[test]
@@ -219,6 +264,58 @@ mod tests {
         assert_eq!(t3.id, t1_id);
     }
 
+    #[test]
+    fn test_no_grad_drops_tape() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Linear<3, 2>, TestDtype>();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let traced = model.forward(x.leaky_trace());
+        let x_traced: Tensor<_, _, _, OwnedTape<TestDtype, _>> = x.leaky_trace();
+        let untraced: Tensor<Rank1<2>, TestDtype, _, NoneTape> = model.forward(x_traced.no_grad());
+        assert_eq!(traced.array(), untraced.array());
+
+        let t = x.leaky_trace().exp();
+        let t_id = t.id;
+        let t = t.no_grad();
+        assert_eq!(t.id, t_id);
+    }
+
+    #[test]
+    fn test_no_grad_records_no_gradients() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let z: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let x_traced: Tensor<_, _, _, OwnedTape<TestDtype, _>> = x.leaky_trace();
+        let loss = (z.leaky_trace() * x_traced.no_grad().exp()).sum();
+        let grads = loss.backward();
+        assert!(grads.get_ref_checked(&x).is_none());
+        assert_close(&grads.get(&z).array(), &x.clone().exp().array());
+
+        let loss = (z.leaky_trace() * x.leaky_trace().exp()).sum();
+        let grads = loss.backward();
+        assert!(grads.get_ref_checked(&x).is_some());
+    }
+
+    #[test]
+    fn test_detach_stops_gradients() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Linear<3, 2>, TestDtype>();
+        let x: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let z: Tensor<Rank1<2>, TestDtype, _> = dev.sample_normal();
+
+        let y = model.forward(x.trace(model.alloc_grads()));
+        let (y, tape) = y.split_tape();
+        let y_detached = y.detach();
+        assert_ne!(y_detached.id, y.id);
+        assert_eq!(y_detached.array(), y.array());
+
+        let loss = (z.clone().put_tape(tape) * y_detached).sum();
+        let grads = loss.backward();
+        assert_eq!(grads.get(&model.weight).array(), [[0.0; 3]; 2]);
+        assert_eq!(grads.get(&model.bias).array(), [0.0; 2]);
+    }
+
     #[test]
     fn test_zeros() {
         let dev: TestDevice = Default::default();
@@ -233,6 +330,72 @@ mod tests {
         assert_eq!(x.array(), [[1.0; 2]; 3]);
     }
 
+    #[test]
+    fn test_full() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, f32, _> = dev.full(5.0);
+        assert_eq!(x.array(), [[5.0; 3]; 2]);
+
+        let y: Tensor<(usize, Const<2>), f32, _> = dev.full_like(&(3, Const), -1.0);
+        assert_eq!(y.as_vec(), [-1.0; 6]);
+    }
+
+    #[test]
+    fn test_arange() {
+        use crate::data::Arange;
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<4>, f32, _> = dev.arange(Const);
+        assert_eq!(x.array(), [0.0, 1.0, 2.0, 3.0]);
+
+        let y: Tensor<(Const<2>, usize), f32, _> = dev.arange_like(&(Const, 3));
+        assert_eq!(y.as_vec(), [0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_from_fn_identity() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> =
+            dev.from_fn::<Rank2<3, 3>>(|[i, j]| if i == j { 1.0 } else { 0.0 });
+        assert_eq!(
+            x.array(),
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+        );
+
+        let y: Tensor<(usize, Const<2>, usize), usize, _> =
+            dev.from_fn_like(&(2, Const, 3), |[i, j, k]| 100 * i + 10 * j + k);
+        assert_eq!(
+            y.as_vec(),
+            [0, 1, 2, 10, 11, 12, 100, 101, 102, 110, 111, 112]
+        );
+    }
+
+    #[test]
+    fn test_from_fn_like_zero_size() {
+        let dev: TestDevice = Default::default();
+        let mut calls = 0;
+        let x: Tensor<(usize, usize), TestDtype, _> = dev.from_fn_like(&(0, 3), |_| {
+            calls += 1;
+            0.0
+        });
+        assert_eq!(calls, 0);
+        assert_eq!(x.shape(), &(0, 3));
+    }
+
+    #[test]
+    fn test_randperm() {
+        let dev: TestDevice = Default::default();
+        let a = dev.randperm(100, 42).as_vec();
+        assert_eq!(a, dev.randperm(100, 42).as_vec());
+        assert_ne!(a, dev.randperm(100, 43).as_vec());
+
+        let mut sorted = a.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..100).collect::<Vec<_>>());
+        assert_ne!(a, sorted);
+
+        assert_eq!(dev.randperm(0, 0).shape(), &(0,));
+    }
+
     #[test]
     fn test_convert_array() {
         let dev: TestDevice = Default::default();
@@ -426,4 +589,120 @@ mod tests {
             ]; 4]; 3]
         );
     }
+
+    #[test]
+    fn test_supports_dtype() {
+        assert!(TestDevice::supports_dtype::<f32>());
+        assert!(TestDevice::supports_dtype::<f64>());
+        assert!(!TestDevice::supports_dtype::<usize>());
+        assert!(!TestDevice::supports_dtype::<bool>());
+    }
+
+    #[test]
+    fn test_to_unshared_does_not_share_data() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let mut b = a.to_unshared();
+        assert_ne!(a.id, b.id);
+        assert!(!std::sync::Arc::ptr_eq(&a.data, &b.data));
+        assert_eq!(a.array(), b.array());
+
+        let orig = a.array();
+        b.copy_from(&[0.0; 6]);
+        assert_eq!(a.array(), orig);
+        assert_eq!(b.array(), [[0.0; 3]; 2]);
+
+        let dev: Cpu = Default::default();
+        let a: Tensor<Rank1<3>, TestDtype, _> = dev.zeros();
+        assert!(a.clone().try_as_mut_slice().is_none());
+        let mut b = a.to_unshared();
+        b.try_as_mut_slice().unwrap()[1] = 2.0;
+        assert_eq!(a.array(), [0.0; 3]);
+        assert_eq!(b.array(), [0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_gradient_norms() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank2<2, 2>, TestDtype, _> = dev.sample_normal();
+        let c: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([1.0, -2.0, 2.0]);
+        let loss = (a.leaky_trace() * c).sum() + b.leaky_trace().square().sum();
+        let g = loss.backward();
+
+        let ga = g.get(&a).as_vec();
+        let gb = g.get(&b).as_vec();
+        let sq_a: TestDtype = ga.iter().map(|x| x * x).sum();
+        let sq_b: TestDtype = gb.iter().map(|x| x * x).sum();
+
+        let norms = g.norms(&dev);
+        assert_close(&norms[a.id()], &3.0);
+        assert_close(&norms[a.id()], &sq_a.sqrt());
+        assert_close(&norms[b.id()], &sq_b.sqrt());
+
+        let mut g = g;
+        g.retain_leafs(&[*a.id(), *b.id()]);
+        assert_eq!(g.norms(&dev).len(), 2);
+        assert_close(&g.global_norm(&dev), &(sq_a + sq_b).sqrt());
+    }
+
+    #[test]
+    fn test_tensor_from_vec_wrong_len() {
+        let dev: Cpu = Default::default();
+        let r = dev.try_tensor_from_vec(vec![0.0f32; 5], (Const::<2>, Const::<3>));
+        let err = r.unwrap_err();
+        assert!(matches!(
+            err,
+            CpuError::WrongNumElements {
+                expected: 6,
+                actual: 5
+            }
+        ));
+        assert_eq!(
+            std::format!("{err}"),
+            "CpuError::WrongNumElements: expected 6 elements, found 5"
+        );
+
+        let dev: TestDevice = Default::default();
+        assert!(dev.try_tensor_from_vec(vec![0.0f32; 7], (2, 3)).is_err());
+        assert!(dev.try_tensor_from_vec(vec![0.0f32; 6], (2, 3)).is_ok());
+    }
+
+    #[test]
+    fn test_rng_advances_and_reseeds() {
+        let dev = TestDevice::seed_from_u64(7);
+        let a: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal();
+        assert_ne!(a.array(), b.array());
+
+        let dev = TestDevice::seed_from_u64(7);
+        let a2: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal();
+        let b2: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal();
+        assert_eq!(a.array(), a2.array());
+        assert_eq!(b.array(), b2.array());
+    }
+
+    #[test]
+    fn test_synchronize_then_read() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([0.0, 1.0, 2.0]);
+        let r = t.clone().exp();
+        dev.synchronize();
+        crate::tests::assert_close(&r.array(), &t.array().map(TestDtype::exp));
+    }
+
+    #[cfg(feature = "cuda")]
+    #[test]
+    fn test_cuda_oom_reports_size() {
+        let dev: Cuda = Default::default();
+        let num_elems = 1 << 50;
+        let r = dev.try_alloc_len::<f32>(num_elems);
+        assert!(matches!(
+            r,
+            Err(CudaError::OutOfMemory { op: "try_alloc_len", num_bytes, .. })
+                if num_bytes == 4 * num_elems
+        ));
+        let msg = std::format!("{}", r.unwrap_err());
+        assert!(msg.contains("try_alloc_len"), "{msg}");
+    }
 }
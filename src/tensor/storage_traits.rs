@@ -1,10 +1,10 @@
-use rand::distributions::Distribution;
+use rand::{distributions::Distribution, rngs::StdRng, Rng, SeedableRng};
 use rand_distr::{Standard, StandardNormal};
 use std::vec::Vec;
 
 use crate::shapes::*;
 
-use super::Tensor;
+use super::{cpu::NdIndex, Tensor};
 
 /// Represents something that has an error associated type
 pub trait HasErr: Sized {
@@ -35,13 +35,31 @@ pub trait DeviceStorage: 'static + std::fmt::Debug + Default + Clone + HasErr {
 
     fn len<E: Unit>(&self, v: &Self::Vec<E>) -> usize;
 
+    /// Copies the raw storage `v` into a host [Vec], in storage order.
+    fn storage_to_vec<E: Unit>(&self, v: &Self::Vec<E>) -> Vec<E>;
+
     /// Blocks until all work on device to complete. Useful for benchmarking.
+    ///
+    /// Kernels may run asynchronously with the host, so timings taken without
+    /// synchronizing only measure kernel launches. Reading a tensor back to the host
+    /// (e.g. with [Tensor::as_vec()]) already waits for pending work on the tensor.
     fn synchronize(&self) {
         self.try_synchronize().unwrap()
     }
 
     /// Blocks until all work on device to complete. Useful for benchmarking.
     fn try_synchronize(&self) -> Result<(), Self::Err>;
+
+    /// Whether tensor ops can run on this device with dtype `E`, i.e. whether
+    /// [crate::tensor_ops::Device] is implemented for `E`. In generic code prefer
+    /// requiring `D: Device<E>`, which checks this at compile time.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// assert!(Cpu::supports_dtype::<f32>());
+    /// assert!(!Cpu::supports_dtype::<usize>());
+    /// ```
+    fn supports_dtype<E: Unit>() -> bool;
 }
 
 /// Internal trait - Represents something that can allocate its own gradient.
@@ -183,6 +201,43 @@ pub trait OneFillStorage<E: Unit>: DeviceStorage {
     fn try_fill_with_ones(&self, storage: &mut Self::Vec<E>) -> Result<(), Self::Err>;
 }
 
+/// Construct tensors filled with a single value.
+pub trait FullTensor<E: Unit>: DeviceStorage {
+    /// Creates a tensor filled with `value`.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.full(5.0);
+    /// assert_eq!(a.array(), [[5.0; 3]; 2]);
+    /// ```
+    fn full<S: ConstShape>(&self, value: E) -> Tensor<S, E, Self> {
+        self.try_full_like::<S>(&Default::default(), value).unwrap()
+    }
+
+    /// Fallible version of [FullTensor::full]
+    fn try_full<S: ConstShape>(&self, value: E) -> Result<Tensor<S, E, Self>, Self::Err> {
+        self.try_full_like::<S>(&Default::default(), value)
+    }
+
+    /// Build the tensor with a shape given by something else.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<(usize, Const<3>), f32, _> = dev.full_like(&(5, Const), -1.0);
+    /// ```
+    fn full_like<S: HasShape>(&self, src: &S, value: E) -> Tensor<S::Shape, E, Self> {
+        self.try_full_like(src, value).unwrap()
+    }
+
+    /// Fallible version of [FullTensor::full_like]
+    fn try_full_like<S: HasShape>(
+        &self,
+        src: &S,
+        value: E,
+    ) -> Result<Tensor<S::Shape, E, Self>, Self::Err>;
+}
+
 /// Build upper & lower triangle tensors.
 pub trait TriangleTensor<E: Unit>: DeviceStorage {
     /// Build a tensor containing the upper triangle part of each lowest 2D matrix
@@ -416,6 +471,9 @@ pub trait TensorFromVec<E: Unit>: DeviceStorage {
         self.try_tensor_from_vec::<S>(src, shape).unwrap()
     }
 
+    /// Fallible version of [TensorFromVec::tensor_from_vec]. Returns an error
+    /// (e.g. [crate::tensor::CpuError::WrongNumElements]) if `src.len()` doesn't
+    /// match the number of elements in `shape`.
     fn try_tensor_from_vec<S: Shape>(
         &self,
         src: Vec<E>,
@@ -423,6 +481,96 @@ pub trait TensorFromVec<E: Unit>: DeviceStorage {
     ) -> Result<Tensor<S, E, Self>, Self::Err>;
 }
 
+/// Construct tensors by calling a function with the index of each element.
+#[allow(clippy::wrong_self_convention)]
+pub trait TensorFromFn<E: Unit>: TensorFromVec<E> {
+    /// Creates a tensor where each element is `f(index)`, with `index` being
+    /// an array of the position along each dimension.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.from_fn::<Rank2<2, 3>>(|[i, j]| (10 * i + j) as f32);
+    /// assert_eq!(t.array(), [[0.0, 1.0, 2.0], [10.0, 11.0, 12.0]]);
+    /// ```
+    fn from_fn<S: ConstShape>(&self, f: impl FnMut(S::Concrete) -> E) -> Tensor<S, E, Self> {
+        self.try_from_fn_like::<S>(&Default::default(), f).unwrap()
+    }
+
+    /// Fallible version of [TensorFromFn::from_fn]
+    fn try_from_fn<S: ConstShape>(
+        &self,
+        f: impl FnMut(S::Concrete) -> E,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        self.try_from_fn_like::<S>(&Default::default(), f)
+    }
+
+    /// Build the tensor with a shape given by something else.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<(usize,), f32, _> = dev.from_fn_like(&(3,), |[i]| i as f32);
+    /// assert_eq!(t.as_vec(), [0.0, 1.0, 2.0]);
+    /// ```
+    fn from_fn_like<S: HasShape>(
+        &self,
+        src: &S,
+        f: impl FnMut(<S::Shape as Shape>::Concrete) -> E,
+    ) -> Tensor<S::Shape, E, Self> {
+        self.try_from_fn_like(src, f).unwrap()
+    }
+
+    /// Fallible version of [TensorFromFn::from_fn_like]
+    fn try_from_fn_like<S: HasShape>(
+        &self,
+        src: &S,
+        mut f: impl FnMut(<S::Shape as Shape>::Concrete) -> E,
+    ) -> Result<Tensor<S::Shape, E, Self>, Self::Err> {
+        let shape = *src.shape();
+        let mut data = Vec::with_capacity(shape.num_elements());
+        if shape.num_elements() > 0 {
+            let mut index = NdIndex::new(shape, shape.strides());
+            while let Some((_, idx)) = index.next_with_idx() {
+                data.push(f(idx));
+            }
+        }
+        self.try_tensor_from_vec(data, shape)
+    }
+}
+impl<E: Unit, D: TensorFromVec<E>> TensorFromFn<E> for D {}
+
+/// Construct random permutations of indices, e.g. for shuffling a dataset.
+pub trait RandpermTensor: TensorFromVec<usize> {
+    /// Creates a random permutation of `0..n`, using a Fisher-Yates shuffle seeded by `seed`.
+    /// The same `seed` always produces the same permutation.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let perm = dev.randperm(5, 0);
+    /// let mut sorted = perm.as_vec();
+    /// sorted.sort();
+    /// assert_eq!(sorted, [0, 1, 2, 3, 4]);
+    /// assert_eq!(perm.as_vec(), dev.randperm(5, 0).as_vec());
+    /// ```
+    fn randperm(&self, n: usize, seed: u64) -> Tensor<(usize,), usize, Self> {
+        self.try_randperm(n, seed).unwrap()
+    }
+
+    /// Fallible version of [RandpermTensor::randperm]
+    fn try_randperm(
+        &self,
+        n: usize,
+        seed: u64,
+    ) -> Result<Tensor<(usize,), usize, Self>, Self::Err> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut data: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            data.swap(i, rng.gen_range(0..=i));
+        }
+        self.try_tensor_from_vec(data, (n,))
+    }
+}
+impl<D: TensorFromVec<usize>> RandpermTensor for D {}
+
 /// Construct tensors from rust data
 pub trait TensorFrom<Src, S: Shape, E: Unit>: DeviceStorage {
     /// Create a tensor from rust data
@@ -100,6 +100,12 @@ impl<S: Shape, E: Unit, F: Unit, D: DeviceStorage> Trace<E, D> for Tensor<S, F,
 }
 
 impl<S: Shape, E: Unit, D: DeviceStorage, T> Tensor<S, E, D, T> {
+    /// The [UniqueId] of this tensor, which its gradients are keyed by
+    /// (e.g. in [Gradients::norms()]).
+    pub fn id(&self) -> &UniqueId {
+        &self.id
+    }
+
     /// Clone and insert a new tape of type `New` into the tensor
     pub fn retaped<New: Tape<E, D>>(&self) -> Tensor<S, E, D, New> {
         Tensor {
@@ -111,6 +117,71 @@ impl<S: Shape, E: Unit, D: DeviceStorage, T> Tensor<S, E, D, T> {
             tape: Default::default(),
         }
     }
+
+    /// Drops the tape of the tensor, so no operations involving the result
+    /// are recorded. Useful for inference, where gradients aren't needed:
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank1<3>, f32, _, OwnedTape<f32, _>> = dev.zeros().leaky_traced();
+    /// let b: Tensor<Rank1<3>, f32, _, NoneTape> = a.no_grad().exp();
+    /// ```
+    ///
+    /// This is an alias for `self.split_tape().0`.
+    pub fn no_grad(self) -> Tensor<S, E, D, NoneTape> {
+        self.split_tape().0
+    }
+
+    /// Creates a new tensor that shares data with `self`, but has a new id and no tape,
+    /// so gradients never flow back through it.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank1<3>, f32, _> = dev.zeros();
+    /// let b = a.leaky_trace().exp();
+    /// let c: Tensor<Rank1<3>, f32, _, NoneTape> = b.detach();
+    /// ```
+    ///
+    /// Unlike [Tensor::no_grad], the result has a different id, so even if it is
+    /// used alongside tensors that have a tape, no gradient is accumulated for `self`.
+    pub fn detach(&self) -> Tensor<S, E, D, NoneTape> {
+        Tensor {
+            id: unique_id(),
+            data: self.data.clone(),
+            shape: self.shape,
+            strides: self.strides,
+            device: self.device.clone(),
+            tape: NoneTape,
+        }
+    }
+
+    /// Like [Tensor::detach], but copies the data into a new buffer that isn't shared
+    /// with any other tensor.
+    ///
+    /// Tensors that share a buffer (e.g. from [Clone] or [Tensor::detach]) are already
+    /// copied on write, so this is only needed to control when that copy happens.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank1<3>, f32, _> = dev.zeros();
+    /// let mut b = a.to_unshared();
+    /// b.try_as_mut_slice().unwrap()[0] = 1.0;
+    /// assert_eq!(a.array(), [0.0; 3]);
+    /// assert_eq!(b.array(), [1.0, 0.0, 0.0]);
+    /// ```
+    pub fn to_unshared(&self) -> Tensor<S, E, D, NoneTape> {
+        Tensor {
+            id: unique_id(),
+            data: Arc::new(self.data.as_ref().clone()),
+            shape: self.shape,
+            strides: self.strides,
+            device: self.device.clone(),
+            tape: NoneTape,
+        }
+    }
 }
 
 /// Put a tape of type `T` into the tensor
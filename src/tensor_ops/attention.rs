@@ -0,0 +1,183 @@
+#![allow(clippy::type_complexity)]
+
+use super::*;
+use crate::{shapes::*, tensor::*};
+
+use num_traits::Float;
+
+/// Scaled dot product attention: `softmax(q * k^T / sqrt(K)) * v`, where `K` is the size
+/// of the last dimension of `q` and `k`.
+///
+/// If `causal` is true, query `i` can only attend to keys `0..=i`.
+///
+/// **Pytorch equivalent**: `F.scaled_dot_product_attention(q, k, v, is_causal=causal)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let q: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+/// let k: Tensor<Rank3<2, 5, 4>, f32, _> = dev.sample_normal();
+/// let v: Tensor<Rank3<2, 5, 6>, f32, _> = dev.sample_normal();
+/// let out: Tensor<Rank3<2, 3, 6>, f32, _> = scaled_dot_product_attention(q, k, v, false);
+/// ```
+///
+/// See [Tensor::scaled_dot_product_attention_with_weights()] to also get the attention weights.
+pub fn scaled_dot_product_attention<B: Dim, S1: Dim, S2: Dim, K: Dim, V: Dim, E, D, T, R1, R2>(
+    q: Tensor<(B, S1, K), E, D, T>,
+    k: Tensor<(B, S2, K), E, D, R1>,
+    v: Tensor<(B, S2, V), E, D, R2>,
+    causal: bool,
+) -> Tensor<(B, S1, V), E, D, T>
+where
+    E: Dtype + Float,
+    D: Device<E> + TriangleTensor<E>,
+    T: Tape<E, D> + Merge<R1> + Merge<R2>,
+    R1: Tape<E, D>,
+    R2: Tape<E, D>,
+{
+    q.scaled_dot_product_attention(k, v, causal)
+}
+
+impl<B: Dim, S1: Dim, K: Dim, E, D, T> Tensor<(B, S1, K), E, D, T>
+where
+    E: Dtype + Float,
+    D: Device<E> + TriangleTensor<E>,
+    T: Tape<E, D>,
+{
+    /// See [scaled_dot_product_attention]
+    pub fn scaled_dot_product_attention<S2: Dim, V: Dim, R1, R2>(
+        self,
+        k: Tensor<(B, S2, K), E, D, R1>,
+        v: Tensor<(B, S2, V), E, D, R2>,
+        causal: bool,
+    ) -> Tensor<(B, S1, V), E, D, T>
+    where
+        T: Merge<R1> + Merge<R2>,
+        R1: Tape<E, D>,
+        R2: Tape<E, D>,
+    {
+        self.try_scaled_dot_product_attention(k, v, causal).unwrap()
+    }
+
+    /// See [scaled_dot_product_attention]
+    pub fn try_scaled_dot_product_attention<S2: Dim, V: Dim, R1, R2>(
+        self,
+        k: Tensor<(B, S2, K), E, D, R1>,
+        v: Tensor<(B, S2, V), E, D, R2>,
+        causal: bool,
+    ) -> Result<Tensor<(B, S1, V), E, D, T>, D::Err>
+    where
+        T: Merge<R1> + Merge<R2>,
+        R1: Tape<E, D>,
+        R2: Tape<E, D>,
+    {
+        self.try_scaled_dot_product_attention_with_weights(k, v, causal)
+            .map(|(out, _)| out)
+    }
+
+    /// Same as [scaled_dot_product_attention], but also returns the attention weights
+    /// (i.e. the output of the softmax) with shape `(B, S1, S2)`.
+    ///
+    /// The weights are not tracked on the tape, so they are only useful for inspection.
+    pub fn scaled_dot_product_attention_with_weights<S2: Dim, V: Dim, R1, R2>(
+        self,
+        k: Tensor<(B, S2, K), E, D, R1>,
+        v: Tensor<(B, S2, V), E, D, R2>,
+        causal: bool,
+    ) -> (Tensor<(B, S1, V), E, D, T>, Tensor<(B, S1, S2), E, D>)
+    where
+        T: Merge<R1> + Merge<R2>,
+        R1: Tape<E, D>,
+        R2: Tape<E, D>,
+    {
+        self.try_scaled_dot_product_attention_with_weights(k, v, causal)
+            .unwrap()
+    }
+
+    /// See [Tensor::scaled_dot_product_attention_with_weights]
+    pub fn try_scaled_dot_product_attention_with_weights<S2: Dim, V: Dim, R1, R2>(
+        self,
+        k: Tensor<(B, S2, K), E, D, R1>,
+        v: Tensor<(B, S2, V), E, D, R2>,
+        causal: bool,
+    ) -> Result<(Tensor<(B, S1, V), E, D, T>, Tensor<(B, S1, S2), E, D>), D::Err>
+    where
+        T: Merge<R1> + Merge<R2>,
+        R1: Tape<E, D>,
+        R2: Tape<E, D>,
+    {
+        let scale = E::ONE / E::from_usize(self.shape.2.size()).unwrap().sqrt();
+        let k = k.try_permute::<(B, K, S2), Axes3<0, 2, 1>>()?;
+        let mut weights = self.try_matmul(k)?.try_mul(scale)?;
+        if causal {
+            let mask = weights
+                .device
+                .try_upper_tri_like(&weights.shape, E::neg_infinity(), 1)?;
+            weights = weights.try_add(mask)?;
+        }
+        let (weights, tape) = weights.try_softmax::<Axis<2>>()?.split_tape();
+        let out = weights.clone().put_tape(tape).try_matmul(v)?;
+        Ok((out, weights))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_sdpa_matches_composed() {
+        let dev: TestDevice = Default::default();
+        let q: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let k: Tensor<Rank3<2, 5, 4>, TestDtype, _> = dev.sample_normal();
+        let v: Tensor<Rank3<2, 5, 6>, TestDtype, _> = dev.sample_normal();
+
+        let (y1, w1) = q.leaky_trace().scaled_dot_product_attention_with_weights(
+            k.leaky_trace(),
+            v.leaky_trace(),
+            false,
+        );
+        let w2 = (q
+            .leaky_trace()
+            .matmul(k.leaky_trace().permute::<_, Axes3<0, 2, 1>>())
+            / 2.0)
+            .softmax::<Axis<2>>();
+        assert_close(&w1.array(), &w2.array());
+        let y2 = w2.matmul(v.leaky_trace());
+        assert_close(&y1.array(), &y2.array());
+
+        let g1 = y1.exp().mean().backward();
+        let g2 = y2.exp().mean().backward();
+        assert_close(&g1.get(&q).array(), &g2.get(&q).array());
+        assert_close(&g1.get(&k).array(), &g2.get(&k).array());
+        assert_close(&g1.get(&v).array(), &g2.get(&v).array());
+    }
+
+    #[test]
+    fn test_sdpa_causal() {
+        let dev: TestDevice = Default::default();
+        let q: Tensor<Rank3<1, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let k: Tensor<Rank3<1, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let v: Tensor<Rank3<1, 3, 2>, TestDtype, _> = dev.sample_normal();
+
+        let (y, w) =
+            q.leaky_trace()
+                .scaled_dot_product_attention_with_weights(k.clone(), v.clone(), true);
+        let w = w.array();
+        assert_eq!(w[0][0], [1.0, 0.0, 0.0]);
+        assert_eq!(w[0][1][2], 0.0);
+        assert_close(&w[0][1].iter().sum(), &1.0);
+        assert_close(&w[0][2].iter().sum(), &1.0);
+
+        // the first query only sees the first value
+        let y = y.array();
+        assert_close(&y[0][0], &v.array()[0][0]);
+
+        // changing the last key doesn't affect the first two outputs
+        let k2 = k.clone() + dev.tensor([[[0.0, 0.0], [0.0, 0.0], [5.0, -5.0]]]);
+        let y2 = scaled_dot_product_attention(q, k2, v, true).array();
+        assert_close(&y[0][0], &y2[0][0]);
+        assert_close(&y[0][1], &y2[0][1]);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{shapes::*, tensor::*};
+
+use std::borrow::Cow;
+
+impl<S: Shape, E: Unit> Tensor<S, E, Cpu> {
+    /// Splits the tensor along its first axis into consecutive batches of `batch_size` rows.
+    /// The last batch has fewer rows if `batch_size` doesn't evenly divide the first axis.
+    ///
+    /// The size of the first axis in each batch is a `usize`. Each batch is a copy,
+    /// and is not tracked on the tape. Contiguous tensors are copied one batch at a
+    /// time, other tensors are first copied into row major order.
+    ///
+    /// Pair with [RandpermTensor::randperm()] and [crate::tensor_ops::SelectTo::gather()] to shuffle the data first.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let batches: Vec<_> = t.batches(2).collect();
+    /// assert_eq!(batches.len(), 2);
+    /// assert_eq!(batches[0].as_vec(), [1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(batches[1].shape(), &(1, Const::<2>));
+    /// ```
+    pub fn batches(
+        &self,
+        batch_size: usize,
+    ) -> impl Iterator<Item = Tensor<S::Resized, E, Cpu>> + '_
+    where
+        S: ResizeDimShape<Axis<0>>,
+    {
+        assert!(batch_size > 0, "batch_size must be greater than 0");
+        let num_rows = self.shape.concrete()[0];
+        let row_len = self.shape.num_elements().checked_div(num_rows).unwrap_or(0);
+        let data = match self.try_as_slice() {
+            Some(data) => Cow::Borrowed(data),
+            None => Cow::Owned(self.as_vec()),
+        };
+        (0..num_rows).step_by(batch_size).map(move |start| {
+            let end = (start + batch_size).min(num_rows);
+            let batch = data[start * row_len..end * row_len].to_vec();
+            self.device
+                .tensor_from_vec(batch, self.shape.resize_dim(end - start))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_batches_uneven() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<10, 4>, f32, _> =
+            dev.from_fn::<Rank2<10, 4>>(|[i, j]| (4 * i + j) as f32);
+        let batches: std::vec::Vec<_> = t.batches(4).collect();
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].shape(), &(4, Const::<4>));
+        assert_eq!(batches[1].shape(), &(4, Const::<4>));
+        assert_eq!(batches[2].shape(), &(2, Const::<4>));
+        let all: std::vec::Vec<f32> = batches.iter().flat_map(|b| b.as_vec()).collect();
+        assert_eq!(all, t.as_vec());
+    }
+
+    #[test]
+    fn test_batches_of_permuted() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let t = t.permute::<Rank2<3, 2>, _>();
+        let batches: std::vec::Vec<_> = t.batches(2).collect();
+        assert_eq!(batches[0].as_vec(), [1.0, 4.0, 2.0, 5.0]);
+        assert_eq!(batches[1].as_vec(), [3.0, 6.0]);
+    }
+}
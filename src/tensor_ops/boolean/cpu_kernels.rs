@@ -1,9 +1,10 @@
 use crate::{
-    shapes::{Shape, Unit},
+    shapes::{Axes, HasAxes, ReduceShapeTo, Shape, Unit},
     tensor::{cpu::LendingIterator, Cpu, HasErr, Tensor, ZerosTensor},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
 };
 
-use super::BooleanKernel;
+use super::{BooleanKernel, BooleanReduceKernel};
 
 impl Cpu {
     fn eval_binary<S: Shape, E: Unit, O: Fn(E, E) -> E>(
@@ -21,6 +22,29 @@ impl Cpu {
         }
         Ok(out)
     }
+
+    fn eval_bool_reduce<Src: Shape, Dst: Shape, Ax: Axes, E: Unit, O: Fn(E, bool) -> E>(
+        &self,
+        dst: Dst,
+        init: E,
+        op: O,
+        inp: &Tensor<Src, bool, Self>,
+    ) -> Result<Tensor<Dst, E, Self>, <Self as HasErr>::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>,
+    {
+        let mut out = self.try_zeros_like(&dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        for o in out.buf_iter_mut() {
+            let mut tmp = init;
+            for _ in 0..num_elems_reduced {
+                tmp = op(tmp, inp.data[idx.next().unwrap()]);
+            }
+            *o = tmp;
+        }
+        Ok(out)
+    }
 }
 
 impl BooleanKernel for Cpu {
@@ -59,3 +83,38 @@ impl BooleanKernel for Cpu {
         self.eval_binary(|l, r| l ^ r, lhs, rhs)
     }
 }
+
+impl BooleanReduceKernel for Cpu {
+    fn any<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Tensor<Src, bool, Self>,
+    ) -> Result<Tensor<Dst, bool, Self>, Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>,
+    {
+        self.eval_bool_reduce(dst, false, |a, x| a || x, inp)
+    }
+
+    fn all<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Tensor<Src, bool, Self>,
+    ) -> Result<Tensor<Dst, bool, Self>, Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>,
+    {
+        self.eval_bool_reduce(dst, true, |a, x| a && x, inp)
+    }
+
+    fn count_nonzero<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Tensor<Src, bool, Self>,
+    ) -> Result<Tensor<Dst, usize, Self>, Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>,
+    {
+        self.eval_bool_reduce(dst, 0, |a, x| a + x as usize, inp)
+    }
+}
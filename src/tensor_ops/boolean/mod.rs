@@ -6,7 +6,7 @@ mod cuda_kernels;
 use crate::{
     prelude::{OnesTensor, Tensor, ZerosTensor},
     shapes::*,
-    tensor::DeviceStorage,
+    tensor::{DeviceStorage, HasErr},
 };
 
 use std::ops::{BitAnd, BitOr, BitXor, Not};
@@ -38,6 +38,32 @@ pub trait BooleanKernel: DeviceStorage + OnesTensor<bool> + ZerosTensor<bool> {
     ) -> Result<Tensor<S, bool, Self>, Self::Err>;
 }
 
+pub trait BooleanReduceKernel: DeviceStorage {
+    fn any<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Tensor<Src, bool, Self>,
+    ) -> Result<Tensor<Dst, bool, Self>, Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>;
+
+    fn all<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Tensor<Src, bool, Self>,
+    ) -> Result<Tensor<Dst, bool, Self>, Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>;
+
+    fn count_nonzero<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Tensor<Src, bool, Self>,
+    ) -> Result<Tensor<Dst, usize, Self>, Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>;
+}
+
 fn scalar_and<D: BooleanKernel, S: Shape>(
     lhs: &Tensor<S, bool, D>,
     rhs: bool,
@@ -252,9 +278,124 @@ pub fn bool_xor<S: Shape, E: Dtype, D: Device<E>>(
     lhs ^ rhs
 }
 
+/// Whether any value is `true` along the axes `Ax`.
+///
+/// **Pytorch equivalent**: `t.any(Ax)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[false, true, false], [false, false, false]]);
+/// assert_eq!(a.any::<Rank1<2>, _>().array(), [true, false]);
+/// assert_eq!(a.any::<Rank0, _>().array(), true);
+/// ```
+pub fn any<Src: Shape, Dst: Shape, Ax: Axes, D: BooleanReduceKernel>(
+    inp: &Tensor<Src, bool, D>,
+) -> Tensor<Dst, bool, D>
+where
+    Src: ReduceShapeTo<Dst, Ax>,
+{
+    inp.any()
+}
+
+/// Whether all values are `true` along the axes `Ax`.
+///
+/// **Pytorch equivalent**: `t.all(Ax)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[true, true, false], [true, true, true]]);
+/// assert_eq!(a.all::<Rank1<2>, _>().array(), [false, true]);
+/// assert_eq!(a.all::<Rank0, _>().array(), false);
+/// ```
+pub fn all<Src: Shape, Dst: Shape, Ax: Axes, D: BooleanReduceKernel>(
+    inp: &Tensor<Src, bool, D>,
+) -> Tensor<Dst, bool, D>
+where
+    Src: ReduceShapeTo<Dst, Ax>,
+{
+    inp.all()
+}
+
+/// Counts the `true` values along the axes `Ax`.
+///
+/// **Pytorch equivalent**: `torch.count_nonzero(t, Ax)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[true, true, false], [false, false, true]]);
+/// assert_eq!(a.count_nonzero::<Rank1<3>, _>().array(), [1, 1, 1]);
+/// assert_eq!(a.count_nonzero::<Rank0, _>().array(), 3);
+/// ```
+pub fn count_nonzero<Src: Shape, Dst: Shape, Ax: Axes, D: BooleanReduceKernel>(
+    inp: &Tensor<Src, bool, D>,
+) -> Tensor<Dst, usize, D>
+where
+    Src: ReduceShapeTo<Dst, Ax>,
+{
+    inp.count_nonzero()
+}
+
+impl<S: Shape, D: BooleanReduceKernel> Tensor<S, bool, D> {
+    /// See [any()]
+    pub fn any<Dst: Shape, Ax: Axes>(&self) -> Tensor<Dst, bool, D>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_any().unwrap()
+    }
+
+    /// See [any()]
+    pub fn try_any<Dst: Shape, Ax: Axes>(&self) -> Result<Tensor<Dst, bool, D>, D::Err>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        self.device.any(self.shape.reduced(), self)
+    }
+
+    /// See [all()]
+    pub fn all<Dst: Shape, Ax: Axes>(&self) -> Tensor<Dst, bool, D>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_all().unwrap()
+    }
+
+    /// See [all()]
+    pub fn try_all<Dst: Shape, Ax: Axes>(&self) -> Result<Tensor<Dst, bool, D>, D::Err>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        self.device.all(self.shape.reduced(), self)
+    }
+
+    /// See [count_nonzero()]
+    pub fn count_nonzero<Dst: Shape, Ax: Axes>(&self) -> Tensor<Dst, usize, D>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_count_nonzero().unwrap()
+    }
+
+    /// See [count_nonzero()]
+    pub fn try_count_nonzero<Dst: Shape, Ax: Axes>(
+        &self,
+    ) -> Result<Tensor<Dst, usize, D>, <Self as HasErr>::Err>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        self.device.count_nonzero(self.shape.reduced(), self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tests::*};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     const TRUTH_TABLE_1: [bool; 4] = [false, false, true, true];
     const TRUTH_TABLE_2: [bool; 4] = [false, true, false, true];
@@ -312,4 +453,35 @@ mod tests {
         assert_eq!(r2.array(), (!&a).array());
         assert_eq!(r3.array(), a.array());
     }
+
+    #[test]
+    fn test_boolean_any_all() {
+        let dev: Cpu = Default::default();
+        let a = dev.tensor([[false, true, false], [false, false, false]]);
+        let b = dev.tensor([[true, true, false], [true, true, true]]);
+
+        assert_eq!(a.any::<Rank1<3>, _>().array(), [false, true, false]);
+        assert_eq!(a.any::<Rank1<2>, _>().array(), [true, false]);
+        assert!(a.any::<Rank0, _>().array());
+        assert!(!(!&a).all::<Rank0, _>().array());
+
+        assert_eq!(b.all::<Rank1<3>, _>().array(), [true, true, false]);
+        assert_eq!(b.all::<Rank1<2>, _>().array(), [false, true]);
+        assert!(!b.all::<Rank0, _>().array());
+        assert!(b.any::<Rank0, _>().array());
+    }
+
+    #[test]
+    fn test_boolean_count_nonzero() {
+        let dev: Cpu = Default::default();
+        let a = dev.tensor([[true, true, false], [false, true, true]]);
+        assert_eq!(a.count_nonzero::<Rank1<3>, _>().array(), [1, 2, 1]);
+        assert_eq!(a.count_nonzero::<Rank1<2>, _>().array(), [2, 2]);
+        assert_eq!(a.count_nonzero::<Rank0, _>().array(), 4);
+
+        // broadcasted values are counted once per logical element
+        let b: Tensor<Rank2<4, 3>, bool, _> = dev.tensor([true, false, true]).broadcast();
+        assert_eq!(b.count_nonzero::<Rank0, _>().array(), 8);
+        assert_eq!(b.any::<Rank1<3>, _>().array(), [true, false, true]);
+    }
 }
@@ -161,4 +161,17 @@ mod tests {
             .backward();
         assert_close(&g.get(&a).array(), &a.array().map(|x| x.exp() / 3.0));
     }
+
+    #[test]
+    fn test_broadcast_is_zero_stride_view() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<4>, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let r = a.leaky_trace().broadcast::<Rank2<3, 4>, _>();
+        assert_eq!(r.strides, [0, 1]);
+        assert!(std::sync::Arc::ptr_eq(&r.data, &a.data));
+        assert_eq!(r.array(), [[1.0, 2.0, 3.0, 4.0]; 3]);
+
+        let g = (r * 2.0).sum().backward();
+        assert_eq!(g.get(&a).array(), [6.0; 4]);
+    }
 }
@@ -238,7 +238,7 @@ impl_cmp_kernel_op!(LeKernelOp, try_le, le, try_scalar_le, scalar_le, "See [le]"
 
 #[cfg(test)]
 mod tests {
-    use crate::{shapes::*, tensor::*, tests::*};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     type TestTensor<const R: usize, const C: usize, E> =
         Tensor<(Const<R>, Const<C>), E, TestDevice>;
@@ -456,6 +456,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cmp_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [3.0, 2.0, 1.0]]);
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([2.0, 2.0, 2.0]);
+        let b = b.broadcast::<Rank2<2, 3>, _>();
+        assert_eq!(
+            a.eq(&b).array(),
+            [[false, true, false], [false, true, false]]
+        );
+        assert_eq!(
+            a.gt(&b).array(),
+            [[false, false, true], [true, false, false]]
+        );
+        assert_eq!(a.le(&b).array(), [[true, true, false], [false, true, true]]);
+    }
+
     #[test]
     #[should_panic]
     fn test_cmp_shape_mismatch() {
@@ -0,0 +1,83 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::{Cpu, Tensor};
+
+use super::{Conv1DKernel, Conv1DOp};
+
+use std::sync::Arc;
+
+impl Conv1DOp {
+    /// The input position that output position `t` sees through kernel element `k`,
+    /// or `None` if that falls in the padding.
+    #[inline(always)]
+    fn inp_idx(&self, t: usize, k: usize) -> Option<usize> {
+        let i = (t * self.stride + k * self.dilation).checked_sub(self.padding)?;
+        (i < self.l_in).then_some(i)
+    }
+}
+
+impl<E: Dtype> Conv1DKernel<E> for Cpu {
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv1DOp,
+        lhs: &Tensor<L, E, Self>,
+        rhs: &Tensor<R, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err> {
+        let [l0, l1, l2] = [lhs.strides[0], lhs.strides[1], lhs.strides[2]];
+        let [r0, r1, r2] = [rhs.strides[0], rhs.strides[1], rhs.strides[2]];
+        let out_buf = Arc::make_mut(&mut out.data);
+        // out is contiguous
+        let mut i_out = 0;
+        for b in 0..op.batch {
+            for o in 0..op.chan_out {
+                for t in 0..op.l_out {
+                    let mut acc = E::default();
+                    for c in 0..op.chan_in {
+                        for k in 0..op.kernel {
+                            if let Some(i) = op.inp_idx(t, k) {
+                                acc += rhs.data[o * r0 + c * r1 + k * r2]
+                                    * lhs.data[b * l0 + c * l1 + i * l2];
+                            }
+                        }
+                    }
+                    out_buf[i_out] = acc;
+                    i_out += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<L: Shape, R: Shape>(
+        &self,
+        op: Conv1DOp,
+        lhs: &Tensor<L, E, Self>,
+        grad_lhs: &mut Self::Vec<E>,
+        rhs: &Tensor<R, E, Self>,
+        grad_rhs: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let [l0, l1, l2] = [lhs.strides[0], lhs.strides[1], lhs.strides[2]];
+        let [r0, r1, r2] = [rhs.strides[0], rhs.strides[1], rhs.strides[2]];
+        let mut i_out = 0;
+        for b in 0..op.batch {
+            for o in 0..op.chan_out {
+                for t in 0..op.l_out {
+                    let g = grad_out[i_out];
+                    i_out += 1;
+                    for c in 0..op.chan_in {
+                        for k in 0..op.kernel {
+                            if let Some(i) = op.inp_idx(t, k) {
+                                let i_lhs = b * l0 + c * l1 + i * l2;
+                                let i_rhs = o * r0 + c * r1 + k * r2;
+                                grad_lhs[i_lhs] += rhs.data[i_rhs] * g;
+                                grad_rhs[i_rhs] += lhs.data[i_lhs] * g;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+#[derive(Debug, Copy, Clone)]
+pub struct Conv1DOp {
+    pub stride: usize,
+    pub padding: usize,
+    pub dilation: usize,
+    pub kernel: usize,
+    pub batch: usize,
+    pub chan_in: usize,
+    pub chan_out: usize,
+    pub l_in: usize,
+    pub l_out: usize,
+}
+
+impl Conv1DOp {
+    fn new(
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+        kernel: usize,
+        [batch, chan_in, l_in]: [usize; 3],
+        chan_out: usize,
+    ) -> Self {
+        assert!(stride > 0 && dilation > 0);
+        let span = dilation * (kernel - 1) + 1;
+        assert!(
+            l_in + 2 * padding >= span,
+            "Padded input length ({}) is smaller than the dilated kernel ({span})",
+            l_in + 2 * padding
+        );
+        Self {
+            stride,
+            padding,
+            dilation,
+            kernel,
+            batch,
+            chan_in,
+            chan_out,
+            l_in,
+            l_out: (l_in + 2 * padding - span) / stride + 1,
+        }
+    }
+}
+
+pub trait Conv1DKernel<E: Dtype>: DeviceStorage {
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv1DOp,
+        lhs: &Tensor<L, E, Self>,
+        rhs: &Tensor<R, E, Self>,
+        out: &mut Tensor<O, E, Self>,
+    ) -> Result<(), Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<L: Shape, R: Shape>(
+        &self,
+        op: Conv1DOp,
+        lhs: &Tensor<L, E, Self>,
+        grad_lhs: &mut Self::Vec<E>,
+        rhs: &Tensor<R, E, Self>,
+        grad_rhs: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// 1d convolution of a batch of sequences `(B, C, L)` with filters `(O, C, K)`.
+/// The output has shape `(B, O, L_out)` where
+/// `L_out = (L + 2 * padding - dilation * (K - 1) - 1) / stride + 1`.
+///
+/// There is no bias, add one with [crate::tensor_ops::BroadcastTo].
+///
+/// **Pytorch equivalent**: `torch.nn.functional.conv1d(x, w, stride=stride, padding=padding, dilation=dilation)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x: Tensor<Rank3<1, 1, 5>, f32, _> = dev.tensor([[[1.0, 2.0, 3.0, 4.0, 5.0]]]);
+/// let w: Tensor<Rank3<1, 1, 3>, f32, _> = dev.tensor([[[1.0, 0.0, -1.0]]]);
+/// let y = conv1d(x, w, 1, 0, 1);
+/// assert_eq!(y.as_vec(), [-2.0, -2.0, -2.0]);
+/// ```
+pub fn conv1d<B: Dim, const C: usize, L: Dim, const O: usize, const K: usize, E, D, T>(
+    x: Tensor<(B, Const<C>, L), E, D, T>,
+    filters: Tensor<Rank3<O, C, K>, E, D>,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+) -> Tensor<(B, Const<O>, usize), E, D, T>
+where
+    E: Dtype,
+    D: Conv1DKernel<E> + ZerosTensor<E>,
+    T: Tape<E, D>,
+{
+    x.conv1d(filters, stride, padding, dilation)
+}
+
+impl<B: Dim, const C: usize, L: Dim, E: Dtype, D, T: Tape<E, D>> Tensor<(B, Const<C>, L), E, D, T>
+where
+    D: Conv1DKernel<E> + ZerosTensor<E>,
+{
+    /// See [conv1d()]
+    pub fn conv1d<const O: usize, const K: usize>(
+        self,
+        filters: Tensor<Rank3<O, C, K>, E, D>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> Tensor<(B, Const<O>, usize), E, D, T> {
+        self.try_conv1d(filters, stride, padding, dilation).unwrap()
+    }
+
+    /// See [conv1d()]
+    pub fn try_conv1d<const O: usize, const K: usize>(
+        self,
+        filters: Tensor<Rank3<O, C, K>, E, D>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> Result<Tensor<(B, Const<O>, usize), E, D, T>, D::Err> {
+        let (batch, _, l) = self.shape;
+        let op = Conv1DOp::new(stride, padding, dilation, K, [batch.size(), C, l.size()], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut tape = ltape.merge(rtape);
+        let mut out = lhs.device.try_zeros_like(&(batch, Const, op.l_out))?;
+        lhs.device.forward(op, &lhs, &rhs, &mut out)?;
+        let lhs_ghost = lhs.ghost();
+        let rhs_ghost = rhs.ghost();
+        let out_ghost = out.ghost();
+        tape.add_backward_op(move |grads| {
+            grads.try_alloc_for(&rhs_ghost)?;
+            grads.try_alloc_for(&lhs_ghost)?;
+            grads.try_alloc_for(&out_ghost)?;
+            let (grad_lhs, grad_rhs, grad_out) =
+                grads.muts_and_ref(&lhs_ghost, &rhs_ghost, &out_ghost);
+            lhs.device
+                .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_conv1d_simple() {
+        let dev: Cpu = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0, 3.0, 4.0, 5.0]]]);
+        let w: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 0.0, -1.0]]]);
+        let y = x.leaky_trace().conv1d(w.clone(), 1, 0, 1);
+        assert_eq!(y.shape(), &(Const::<1>, Const::<1>, 3));
+        assert_eq!(y.as_vec(), [-2.0, -2.0, -2.0]);
+        let g = y.sum().backward();
+        assert_eq!(g.get(&x).array(), [[[1.0, 1.0, 0.0, -1.0, -1.0]]]);
+        assert_eq!(g.get(&w).array(), [[[6.0, 9.0, 12.0]]]);
+    }
+
+    #[test]
+    fn test_conv1d_stride_padding_dilation() {
+        let dev: Cpu = Default::default();
+        #[rustfmt::skip]
+        let x: Tensor<Rank3<2, 2, 7>, TestDtype, _> = dev.tensor([
+            [[-0.52, 0.09, -0.26, 0.21, 0.25, -0.87, -0.97], [0.67, -0.48, -0.53, 0.99, -0.06, 0.67, -0.05]],
+            [[0.28, -0.7, 0.27, 0.74, 0.05, 0.48, 0.34], [-0.87, 0.52, 0.18, -0.4, -0.94, 0.73, -0.05]],
+        ]);
+        let w: Tensor<Rank3<3, 2, 3>, TestDtype, _> = dev.tensor([
+            [[0.44, 0.76, 0.43], [0.84, -0.21, 0.6]],
+            [[-0.11, 0.87, 0.76], [-0.81, -0.73, -0.57]],
+            [[0.93, -0.13, 0.25], [-0.4, 0.01, -0.23]],
+        ]);
+        let bias: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([-0.3, 0.17, 0.17]);
+
+        let y = x.leaky_trace().conv1d(w.clone(), 2, 2, 2);
+        let y_shape = *y.shape();
+        assert_eq!(y_shape, (Const::<2>, Const::<3>, 4));
+        let y_arr = y
+            .retaped::<NoneTape>()
+            .realize::<Rank3<2, 3, 4>>()
+            .unwrap()
+            .array();
+        #[rustfmt::skip]
+        assert_close(
+            &y_arr,
+            &[
+                [[-0.9657, 0.3192, -0.8041, -0.6671], [-0.837, -0.1006, 0.0105, -0.7863], [0.1312, -0.6468, -0.2939, 0.3821]],
+                [[0.6196, -0.9827, 0.6216, -0.4987], [0.9813, 1.3512, 0.8411, 1.0882], [-0.019, 0.8038, 0.2597, 0.3778]],
+            ],
+        );
+
+        let y = y + bias.leaky_trace().broadcast_like(&y_shape);
+        let g = y.exp().mean().backward();
+        #[rustfmt::skip]
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                [[0.05804671, 0.0, 0.13797918, 0.0, 0.17960593, 0.0, 0.07527692], [-0.02835904, 0.0, -0.1029017, 0.0, -0.0787521, 0.0, -0.04765301]],
+                [[0.2386374, 0.0, 0.36953822, 0.0, 0.37163964, 0.0, 0.26020721], [-0.29667308, 0.0, -0.26250003, 0.0, -0.35404396, 0.0, -0.15577897]],
+            ],
+        );
+        #[rustfmt::skip]
+        assert_close(
+            &g.get(&w).array(),
+            &[
+                [[-0.00202795, -0.00063986, 0.02976938], [0.00285827, -0.11904104, -0.01287724]],
+                [[0.06108438, 0.1118953, 0.04124498], [-0.28103692, -0.20874801, -0.17781218]],
+                [[0.04685698, -0.02594354, -0.00350991], [-0.15872257, -0.06789992, -0.13142969]],
+            ],
+        );
+        assert_close(&g.get(&bias).array(), &[0.22900964, 0.72210776, 0.48624317]);
+    }
+}
@@ -5,6 +5,7 @@ struct Conv2DOp {
     size_t batch;
     size_t chan_in;
     size_t chan_out;
+    size_t groups;
     size_t h_in;
     size_t h_out;
     size_t w_in;
@@ -92,12 +93,14 @@ __device__ void unfold_output_into_patches(
 template<typename T>
 __device__ void transpose_filters(
     const Conv2DOp op,
-    const T *filters, // 4d (ChanOut, ChanIn, KernelSize, KernelSize)
+    const T *filters, // 4d (ChanOut, ChanIn / Groups, KernelSize, KernelSize)
     const size_t *strides, // 4d filters strides
-    T *filters_tr // 4d (ChanIn, ChanOut, KernelSize, KernelSize)
+    T *filters_tr // 4d (ChanIn, ChanOut / Groups, KernelSize, KernelSize)
 ) {
+    const size_t c_per_group = op.chan_in / op.groups;
+    const size_t o_per_group = op.chan_out / op.groups;
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
-    if (i >= op.chan_in * op.chan_out * op.kernel * op.kernel) {
+    if (i >= op.chan_in * o_per_group * op.kernel * op.kernel) {
         return;
     }
 
@@ -106,11 +109,13 @@ __device__ void transpose_filters(
     idx /= op.kernel;
     const size_t k1 = idx % op.kernel;
     idx /= op.kernel;
-    const size_t o = idx % op.chan_out;
-    idx /= op.chan_out;
+    const size_t og = idx % o_per_group;
+    idx /= o_per_group;
     const size_t c = idx % op.chan_in;
+    const size_t o = (c / c_per_group) * o_per_group + og;
+    const size_t cg = c % c_per_group;
 
-    auto i_no = o * strides[0] + c * strides[1] + k1 * strides[2] + k2 * strides[3];
+    auto i_no = o * strides[0] + cg * strides[1] + k1 * strides[2] + k2 * strides[3];
 
     filters_tr[i] = filters[i_no];
 }
@@ -118,12 +123,14 @@ __device__ void transpose_filters(
 template<typename T>
 __device__ void sum_transposed_filters(
     const Conv2DOp op,
-    const T *filters_tr, // 5d (Batch, ChanIn, ChanOut, KernelSize, KernelSize)
-    T *filters, // 4d (ChanOut, ChanIn, KernelSize, KernelSize)
+    const T *filters_tr, // 5d (Batch, ChanIn, ChanOut / Groups, KernelSize, KernelSize)
+    T *filters, // 4d (ChanOut, ChanIn / Groups, KernelSize, KernelSize)
     const size_t *strides // 4d filter strides
 ) {
+    const size_t c_per_group = op.chan_in / op.groups;
+    const size_t o_per_group = op.chan_out / op.groups;
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
-    auto numel = op.chan_out * op.chan_in * op.kernel * op.kernel;
+    auto numel = op.chan_out * c_per_group * op.kernel * op.kernel;
     if (i >= numel) {
         return;
     }
@@ -133,13 +140,15 @@ __device__ void sum_transposed_filters(
     idx /= op.kernel;
     const size_t k1 = idx % op.kernel;
     idx /= op.kernel;
-    const size_t c = idx % op.chan_in;
-    idx /= op.chan_in;
+    const size_t cg = idx % c_per_group;
+    idx /= c_per_group;
     const size_t o = idx % op.chan_out;
     idx /= op.chan_out;
+    const size_t c = (o / o_per_group) * c_per_group + cg;
+    const size_t og = o % o_per_group;
 
-    auto i_tr = c * (op.chan_out * op.kernel * op.kernel) + o * (op.kernel * op.kernel) + k1 * (op.kernel) + k2;
-    auto i_no = o * strides[0] + c * strides[1] + k1 * strides[2] + k2 * strides[3];
+    auto i_tr = c * (o_per_group * op.kernel * op.kernel) + og * (op.kernel * op.kernel) + k1 * (op.kernel) + k2;
+    auto i_no = o * strides[0] + cg * strides[1] + k1 * strides[2] + k2 * strides[3];
 
     filters_tr += i_tr;
 
@@ -37,6 +37,15 @@ impl Conv2DOp {
 
         Some([oh, ow])
     }
+
+    /// Offset into the filters of input channel `c` and output channel `o` within `c`'s group.
+    #[inline(always)]
+    fn filter_idx(&self, c: usize, o: usize, [s0, s1]: [usize; 2]) -> usize {
+        let c_per_group = self.chan_in / self.groups;
+        let o_per_group = self.chan_out / self.groups;
+        let g = c / c_per_group;
+        (g * o_per_group + o) * s0 + (c % c_per_group) * s1
+    }
 }
 
 impl Cpu {
@@ -76,19 +85,22 @@ impl Cpu {
             }
         }
 
-        // (O, C * K * K) * (C * K * K, OH * OW) = (O, OH * OW)
-        let m = op.chan_out;
-        let k = op.chan_in * op.kernel * op.kernel;
+        // for each group g:
+        // (O / G, C / G * K * K) * (C / G * K * K, OH * OW) = (O / G, OH * OW)
+        let m = op.chan_out / op.groups;
+        let k = (op.chan_in / op.groups) * op.kernel * op.kernel;
         let n = op.w_out * op.h_out;
-        Self::matmul(
-            (m, k, n),
-            filters.as_ptr(),
-            [k, 1],
-            buf.as_ptr(),
-            [n, 1],
-            out.as_mut_ptr(),
-            [n, 1],
-        );
+        for g in 0..op.groups {
+            Self::matmul(
+                (m, k, n),
+                filters[g * m * k..].as_ptr(),
+                [k, 1],
+                buf[g * k * n..].as_ptr(),
+                [n, 1],
+                out[g * m * n..].as_mut_ptr(),
+                [n, 1],
+            );
+        }
         Ok(())
     }
 
@@ -130,37 +142,41 @@ impl Cpu {
         }
 
         {
-            // img_g += filters^T * unfold(grad_out)
-            // (C, H * W) += (C, O * K * K) * (O * K * K, H * W)
-            let m = op.chan_in;
-            let k = op.chan_out * op.kernel * op.kernel;
+            // img_g += filters^T * unfold(grad_out), for each group g:
+            // (C / G, H * W) += (C / G, O / G * K * K) * (O / G * K * K, H * W)
+            let m = op.chan_in / op.groups;
+            let k = (op.chan_out / op.groups) * op.kernel * op.kernel;
             let n = op.h_in * op.w_in;
-            Self::matmul(
-                (m, k, n),
-                filters_tr.as_ptr(),
-                [k, 1],
-                buf.as_ptr(),
-                [n, 1],
-                grad_img.as_mut_ptr(),
-                [n, 1],
-            );
+            for g in 0..op.groups {
+                Self::matmul(
+                    (m, k, n),
+                    filters_tr[g * m * k..].as_ptr(),
+                    [k, 1],
+                    buf[g * k * n..].as_ptr(),
+                    [n, 1],
+                    grad_img[g * m * n..].as_mut_ptr(),
+                    [n, 1],
+                );
+            }
         }
 
         {
-            // weight_g^T += img * patches^T
-            // (C, O * K * K) += (C, H * W) * (H * W, O * K * K)
-            let m = op.chan_in;
+            // weight_g^T += img * patches^T, for each group g:
+            // (C / G, O / G * K * K) += (C / G, H * W) * (H * W, O / G * K * K)
+            let m = op.chan_in / op.groups;
             let k = op.h_in * op.w_in;
-            let n = op.chan_out * op.kernel * op.kernel;
-            Self::matmul(
-                (m, k, n),
-                img.as_ptr(),
-                [k, 1],
-                buf.as_ptr(),
-                [1, k],
-                grad_filters_tr.as_mut_ptr(),
-                [n, 1],
-            );
+            let n = (op.chan_out / op.groups) * op.kernel * op.kernel;
+            for g in 0..op.groups {
+                Self::matmul(
+                    (m, k, n),
+                    img[g * m * k..].as_ptr(),
+                    [k, 1],
+                    buf[g * n * k..].as_ptr(),
+                    [1, k],
+                    grad_filters_tr[g * m * n..].as_mut_ptr(),
+                    [n, 1],
+                );
+            }
         }
         Ok(())
     }
@@ -218,12 +234,12 @@ where
         let mut grad_f1023 = self.try_alloc_zeros::<E>(f_tr_shape.num_elements())?;
 
         {
-            // transpose filters in f1023
+            // transpose filters in f1023. with groups, each input channel c only
+            // has the output channels of its own group
             let buf = rhs.data.as_ref();
             let mut f_idx = NdIndex::new(f_tr_shape, f_tr_shape.strides());
             while let Some((i, [c, o, k1, k2])) = f_idx.next_with_idx() {
-                let idx = o * rhs.strides[0]
-                    + c * rhs.strides[1]
+                let idx = op.filter_idx(c, o, [rhs.strides[0], rhs.strides[1]])
                     + k1 * rhs.strides[2]
                     + k2 * rhs.strides[3];
                 f1023[i] = buf[idx];
@@ -253,8 +269,7 @@ where
             // untranspose filters
             let mut f_idx = NdIndex::new(f_tr_shape, f_tr_shape.strides());
             while let Some((i, [c, o, k1, k2])) = f_idx.next_with_idx() {
-                let idx = o * rhs.strides[0]
-                    + c * rhs.strides[1]
+                let idx = op.filter_idx(c, o, [rhs.strides[0], rhs.strides[1]])
                     + k1 * rhs.strides[2]
                     + k2 * rhs.strides[3];
                 grad_rhs[idx] += grad_f1023[i];
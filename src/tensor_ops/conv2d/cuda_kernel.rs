@@ -84,22 +84,27 @@ where
         let params = (op, lhs.data.as_ref(), &img_strides, &mut patches);
         unsafe { unfold_fn.launch(cfg, params) }?;
 
-        // (O, C * K * K) * (B, C * K * K, OH * OW) = (B, O, OH * OW)
-        let m = op.chan_out;
-        let k = op.chan_in * op.kernel * op.kernel;
+        // for each group g:
+        // (O / G, C / G * K * K) * (B, C / G * K * K, OH * OW) = (B, O / G, OH * OW)
+        let g = op.groups;
+        let m = op.chan_out / g;
+        let k = (op.chan_in / g) * op.kernel * op.kernel;
         let n = op.h_out * op.w_out;
-        unsafe {
-            self.gemm_batch(
-                (op.batch, m, k, n),
-                rhs.data.as_ref(),
-                [0, k, 1],
-                &patches,
-                [k * n, n, 1],
-                Default::default(),
-                Arc::get_mut(&mut out.data).unwrap(),
-                [m * n, n, 1],
-            )
-            .unwrap();
+        let out = Arc::get_mut(&mut out.data).unwrap();
+        for i_group in 0..g {
+            unsafe {
+                self.gemm_batch(
+                    (op.batch, m, k, n),
+                    &rhs.data.slice(i_group * m * k..),
+                    [0, k, 1],
+                    &patches.slice(i_group * k * n..),
+                    [g * k * n, n, 1],
+                    Default::default(),
+                    &mut out.slice_mut(i_group * m * n..),
+                    [g * m * n, n, 1],
+                )
+                .unwrap();
+            }
         }
 
         Ok(())
@@ -117,7 +122,7 @@ where
     ) -> Result<(), Self::Err> {
         let patches_item_numel = op.chan_out * op.kernel * op.kernel * op.h_in * op.w_in;
         let patches_numel = op.batch * patches_item_numel;
-        let filters_numel = op.chan_in * op.chan_out * op.kernel * op.kernel;
+        let filters_numel = op.chan_in * (op.chan_out / op.groups) * op.kernel * op.kernel;
 
         let mut patches = unsafe { self.get_workspace::<E>(patches_numel) }?;
         let mut patches = unsafe { patches.transmute_mut::<E>(patches_numel).unwrap() };
@@ -150,46 +155,52 @@ where
 
             self.par_stream.wait_for_default()?;
 
-            // img_g += filters * patches
-            // (B, C, H * W) += (B, C, O * K * K) * (B, O * K * K, H * W)
-            let m = op.chan_in;
-            let k = op.chan_out * op.kernel * op.kernel;
+            // img_g += filters * patches, for each group g:
+            // (B, C / G, H * W) += (B, C / G, O / G * K * K) * (B, O / G * K * K, H * W)
+            let g = op.groups;
+            let m = op.chan_in / g;
+            let k = (op.chan_out / g) * op.kernel * op.kernel;
             let n = op.h_in * op.w_in;
             unsafe {
                 self.blas.set_stream(Some(self.par_stream.as_ref()))?;
-                self.gemm_batch(
-                    (op.batch, m, k, n),
-                    &f_b1023,
-                    [0, k, 1],
-                    &patches,
-                    [k * n, n, 1],
-                    <E>::ONE,
-                    grad_lhs,
-                    [m * n, n, 1],
-                )
-                .unwrap();
+                for i_group in 0..g {
+                    self.gemm_batch(
+                        (op.batch, m, k, n),
+                        &f_b1023.slice(i_group * m * k..),
+                        [0, k, 1],
+                        &patches.slice(i_group * k * n..),
+                        [g * k * n, n, 1],
+                        <E>::ONE,
+                        &mut grad_lhs.slice_mut(i_group * m * n..),
+                        [g * m * n, n, 1],
+                    )
+                    .unwrap();
+                }
                 self.blas.set_stream(None)?;
             }
         }
 
         {
-            // weight_g += img * patches^T
-            // (B, C, O * K * K) += (B, C, H * W) * (B, H * W, O * K * K)
-            let m = op.chan_in;
+            // weight_g += img * patches^T, for each group g:
+            // (B, C / G, O / G * K * K) += (B, C / G, H * W) * (B, H * W, O / G * K * K)
+            let g = op.groups;
+            let m = op.chan_in / g;
             let k = op.h_in * op.w_in;
-            let n = op.chan_out * op.kernel * op.kernel;
-            unsafe {
-                self.gemm_batch(
-                    (op.batch, m, k, n),
-                    lhs.data.as_ref(),
-                    [m * k, k, 1],
-                    &patches,
-                    [k * n, 1, k],
-                    Default::default(),
-                    &mut grad_f_b1023,
-                    [m * n, n, 1],
-                )
-                .unwrap();
+            let n = (op.chan_out / g) * op.kernel * op.kernel;
+            for i_group in 0..g {
+                unsafe {
+                    self.gemm_batch(
+                        (op.batch, m, k, n),
+                        &lhs.data.slice(i_group * m * k..),
+                        [g * m * k, k, 1],
+                        &patches.slice(i_group * k * n..),
+                        [g * k * n, 1, k],
+                        Default::default(),
+                        &mut grad_f_b1023.slice_mut(i_group * m * n..),
+                        [g * m * n, n, 1],
+                    )
+                    .unwrap();
+                }
             }
 
             // sum all the gradients collected in our broadcasted grad_f
@@ -17,6 +17,7 @@ pub(super) struct Conv2DOp {
     pub batch: usize,
     pub chan_in: usize,
     pub chan_out: usize,
+    pub groups: usize,
     pub h_in: usize,
     pub h_out: usize,
     pub w_in: usize,
@@ -24,7 +25,15 @@ pub(super) struct Conv2DOp {
 }
 
 impl Conv2DOp {
-    fn new(s: usize, p: usize, k: usize, [b, c, h_in, w_in]: [usize; 4], o: usize) -> Self {
+    fn new(
+        s: usize,
+        p: usize,
+        k: usize,
+        g: usize,
+        [b, c, h_in, w_in]: [usize; 4],
+        o: usize,
+    ) -> Self {
+        assert!(g > 0 && c % g == 0 && o % g == 0);
         Self {
             stride: s,
             padding: p,
@@ -32,6 +41,7 @@ impl Conv2DOp {
             batch: b,
             chan_in: c,
             chan_out: o,
+            groups: g,
             h_in,
             h_out: (h_in + 2 * p - k) / s + 1,
             w_in,
@@ -50,7 +60,12 @@ impl Conv2DOp {
     }
 
     pub(super) fn filters_tr_shape(&self) -> (usize, usize, usize, usize) {
-        (self.chan_in, self.chan_out, self.kernel, self.kernel)
+        (
+            self.chan_in,
+            self.chan_out / self.groups,
+            self.kernel,
+            self.kernel,
+        )
     }
 }
 
@@ -112,6 +127,19 @@ pub trait TryConv2DTo<F, const S: usize, const P: usize>: HasErr {
     fn try_conv2d_to(self, filters: F) -> Result<Self::Output, Self::Err>;
 }
 
+/// Grouped convolution with `G` groups. The input and output channels are split into `G`
+/// groups, and each output group only sees its own input group. So filters have shape
+/// `(O, C / G, K, K)`. `G == C` is a depthwise convolution.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.conv2d(x, w, stride=S, padding=P, groups=G)`
+pub trait TryConv2DGroupedTo<F, const S: usize, const P: usize, const G: usize>: HasErr {
+    type Output;
+    fn conv2d_grouped_to(self, filters: F) -> Self::Output {
+        self.try_conv2d_grouped_to(filters).unwrap()
+    }
+    fn try_conv2d_grouped_to(self, filters: F) -> Result<Self::Output, Self::Err>;
+}
+
 pub trait TryConv2D<F> {
     fn conv2d<const S: usize, const P: usize>(self, filters: F) -> Self::Output
     where
@@ -128,10 +156,69 @@ pub trait TryConv2D<F> {
     {
         self.try_conv2d_to(filters)
     }
+
+    /// See [TryConv2DGroupedTo]
+    ///
+    /// A depthwise convolution:
+    /// ```ignore
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let x: Tensor<Rank3<4, 8, 8>, f32, _> = dev.sample_normal();
+    /// let w: Tensor<Rank4<4, 1, 3, 3>, f32, _> = dev.sample_normal();
+    /// let y = x.conv2d_grouped::<1, 1, 4>(w);
+    /// ```
+    fn conv2d_grouped<const S: usize, const P: usize, const G: usize>(
+        self,
+        filters: F,
+    ) -> <Self as TryConv2DGroupedTo<F, S, P, G>>::Output
+    where
+        Self: TryConv2DGroupedTo<F, S, P, G>,
+    {
+        self.conv2d_grouped_to(filters)
+    }
+    /// See [TryConv2DGroupedTo]
+    fn try_conv2d_grouped<const S: usize, const P: usize, const G: usize>(
+        self,
+        filters: F,
+    ) -> Result<<Self as TryConv2DGroupedTo<F, S, P, G>>::Output, Self::Err>
+    where
+        Self: TryConv2DGroupedTo<F, S, P, G>,
+    {
+        self.try_conv2d_grouped_to(filters)
+    }
 }
 
 impl<S: Shape, E: Dtype, D: DeviceStorage, T, F> TryConv2D<F> for Tensor<S, E, D, T> {}
 
+fn try_conv2d_op<L: Shape, R: Shape, O: Shape, E: Dtype, D, T>(
+    op: Conv2DOp,
+    img: Tensor<L, E, D, T>,
+    filters: Tensor<R, E, D>,
+    out_shape: O,
+) -> Result<Tensor<O, E, D, T>, D::Err>
+where
+    D: Conv2DKernel<E>,
+    T: 'static + Tape<E, D>,
+{
+    let (lhs, ltape) = img.split_tape();
+    let (rhs, rtape) = filters.split_tape();
+    let mut tape = ltape.merge(rtape);
+    let mut out = lhs.device.alloc(out_shape)?;
+    lhs.device.forward(op, &lhs, &rhs, &mut out)?;
+    let lhs_ghost = lhs.ghost();
+    let rhs_ghost = rhs.ghost();
+    let out_ghost = out.ghost();
+    tape.add_backward_op(move |grads| {
+        grads.try_alloc_for(&rhs_ghost)?;
+        grads.try_alloc_for(&lhs_ghost)?;
+        grads.try_alloc_for(&out_ghost)?;
+        let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs_ghost, &rhs_ghost, &out_ghost);
+        lhs.device
+            .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, &out_ghost, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
 impl<
         const C: usize,
         H: Dim + ConvAlgebra<K, S, P>,
@@ -153,28 +240,9 @@ impl<
     ) -> Result<Self::Output, Self::Err> {
         let h = self.shape.1;
         let w = self.shape.2;
-
-        let op = Conv2DOp::new(S, P, K, [1, C, h.size(), w.size()], O);
-        let (lhs, ltape) = self.split_tape();
-        let (rhs, rtape) = filters.split_tape();
-        let mut tape = ltape.merge(rtape);
-        let mut out = lhs
-            .device
-            .alloc((Const, h.convolve_dim(), w.convolve_dim()))?;
-        lhs.device.forward(op, &lhs, &rhs, &mut out)?;
-        let lhs_ghost = lhs.ghost();
-        let rhs_ghost = rhs.ghost();
-        let out_ghost = out.ghost();
-        tape.add_backward_op(move |grads| {
-            grads.try_alloc_for(&rhs_ghost)?;
-            grads.try_alloc_for(&lhs_ghost)?;
-            grads.try_alloc_for(&out_ghost)?;
-            let (grad_lhs, grad_rhs, grad_out) =
-                grads.muts_and_ref(&lhs_ghost, &rhs_ghost, &out_ghost);
-            lhs.device
-                .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, &out_ghost, grad_out)
-        });
-        Ok(out.put_tape(tape))
+        let op = Conv2DOp::new(S, P, K, 1, [1, C, h.size(), w.size()], O);
+        let out_shape = (Const, h.convolve_dim(), w.convolve_dim());
+        try_conv2d_op(op, self, filters, out_shape)
     }
 }
 
@@ -200,28 +268,69 @@ impl<
         let batch = self.shape().0;
         let h = self.shape().2;
         let w = self.shape().3;
+        let op = Conv2DOp::new(S, P, K, 1, [batch.size(), C, h.size(), w.size()], O);
+        let out_shape = (batch, Const, h.convolve_dim(), w.convolve_dim());
+        try_conv2d_op(op, self, filters, out_shape)
+    }
+}
 
-        let op = Conv2DOp::new(S, P, K, [batch.size(), C, h.size(), w.size()], O);
-        let (lhs, ltape) = self.split_tape();
-        let (rhs, rtape) = filters.split_tape();
-        let mut out = lhs
-            .device
-            .alloc((batch, Const, h.convolve_dim(), w.convolve_dim()))?;
-        let mut tape = ltape.merge(rtape);
-        lhs.device.forward(op, &lhs, &rhs, &mut out)?;
-        let lhs_ghost = lhs.ghost();
-        let rhs_ghost = rhs.ghost();
-        let out_ghost = out.ghost();
-        tape.add_backward_op(move |grads| {
-            grads.try_alloc_for(&rhs_ghost)?;
-            grads.try_alloc_for(&lhs_ghost)?;
-            grads.try_alloc_for(&out_ghost)?;
-            let (grad_lhs, grad_rhs, grad_out) =
-                grads.muts_and_ref(&lhs_ghost, &rhs_ghost, &out_ghost);
-            lhs.device
-                .backward(op, &lhs, grad_lhs, &rhs, grad_rhs, &out_ghost, grad_out)
-        });
-        Ok(out.put_tape(tape))
+impl<
+        const C: usize,
+        H: Dim + ConvAlgebra<K, S, P>,
+        W: Dim + ConvAlgebra<K, S, P>,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        const G: usize,
+        E: Dtype,
+        D: Conv2DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<E, D>,
+    > TryConv2DGroupedTo<Tensor<Rank4<O, { C / G }, K, K>, E, D>, S, P, G>
+    for Tensor<(Const<C>, H, W), E, D, T>
+{
+    type Output = Tensor<(Const<O>, H::Convolved, W::Convolved), E, D, T>;
+
+    fn try_conv2d_grouped_to(
+        self,
+        filters: Tensor<Rank4<O, { C / G }, K, K>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let h = self.shape.1;
+        let w = self.shape.2;
+        let op = Conv2DOp::new(S, P, K, G, [1, C, h.size(), w.size()], O);
+        let out_shape = (Const, h.convolve_dim(), w.convolve_dim());
+        try_conv2d_op(op, self, filters, out_shape)
+    }
+}
+
+impl<
+        B: Dim,
+        const C: usize,
+        H: Dim + ConvAlgebra<K, S, P>,
+        W: Dim + ConvAlgebra<K, S, P>,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        const G: usize,
+        E: Dtype,
+        D: Conv2DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<E, D>,
+    > TryConv2DGroupedTo<Tensor<Rank4<O, { C / G }, K, K>, E, D>, S, P, G>
+    for Tensor<(B, Const<C>, H, W), E, D, T>
+{
+    type Output = Tensor<(B, Const<O>, H::Convolved, W::Convolved), E, D, T>;
+
+    fn try_conv2d_grouped_to(
+        self,
+        filters: Tensor<Rank4<O, { C / G }, K, K>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let batch = self.shape().0;
+        let h = self.shape().2;
+        let w = self.shape().3;
+        let op = Conv2DOp::new(S, P, K, G, [batch.size(), C, h.size(), w.size()], O);
+        let out_shape = (batch, Const, h.convolve_dim(), w.convolve_dim());
+        try_conv2d_op(op, self, filters, out_shape)
     }
 }
 
@@ -454,4 +563,59 @@ mod tests {
             assert_close(&x0, &x_grad.clone().select(dev.tensor(i)).array());
         }
     }
+
+    #[test]
+    fn test_conv2d_depthwise() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<1, 4, 8, 8>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank4<4, 1, 3, 3>, TestDtype, _> = dev.sample_normal();
+
+        let y = x.leaky_trace().conv2d_grouped::<1, 1, 4>(w.clone());
+        let y_arr = y.array();
+        let g = y.exp().sum().backward();
+        let (x_arr, w_arr) = (x.array(), w.array());
+        let (gx, gw) = (g.get(&x).array(), g.get(&w).array());
+
+        // each channel is convolved with its own filter
+        for c in 0..4 {
+            let x_c: Tensor<Rank4<1, 1, 8, 8>, TestDtype, _> = dev.tensor([[x_arr[0][c]]]);
+            let w_c: Tensor<Rank4<1, 1, 3, 3>, TestDtype, _> = dev.tensor([w_arr[c]]);
+            let y_c = x_c.leaky_trace().conv2d::<1, 1>(w_c.clone());
+            assert_close(&y_arr[0][c], &y_c.array()[0][0]);
+            let g_c = y_c.exp().sum().backward();
+            assert_close(&gx[0][c], &g_c.get(&x_c).array()[0][0]);
+            assert_close(&gw[c], &g_c.get(&w_c).array()[0]);
+        }
+    }
+
+    #[test]
+    fn test_conv2d_grouped_matches_block_diagonal() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<4, 5, 5>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank4<6, 2, 2, 2>, TestDtype, _> = dev.sample_normal();
+
+        // a full convolution with the filters of other groups zeroed out
+        let w_arr = w.array();
+        let mut full_arr = [[[[0.0; 2]; 2]; 4]; 6];
+        for o in 0..6 {
+            for c in 0..2 {
+                full_arr[o][(o / 3) * 2 + c] = w_arr[o][c];
+            }
+        }
+        let full: Tensor<Rank4<6, 4, 2, 2>, TestDtype, _> = dev.tensor(full_arr);
+
+        let y = x.leaky_trace().conv2d_grouped::<2, 1, 2>(w.clone());
+        let y_full = x.leaky_trace().conv2d::<2, 1>(full.clone());
+        assert_close(&y.array(), &y_full.array());
+
+        let g = y.exp().sum().backward();
+        let g_full = y_full.exp().sum().backward();
+        assert_close(&g.get(&x).array(), &g_full.get(&x).array());
+        let (gw, gw_full) = (g.get(&w).array(), g_full.get(&full).array());
+        for o in 0..6 {
+            for c in 0..2 {
+                assert_close(&gw[o][c], &gw_full[o][(o / 3) * 2 + c]);
+            }
+        }
+    }
 }
@@ -345,6 +345,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_convtrans2d_is_conv2d_backward() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 7, 7>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank4<3, 2, 3, 3>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank3<3, 4, 4>, TestDtype, _> = dev.sample_normal();
+        // NOTE: reshape makes the permuted filters contiguous, which forward expects
+        let w_tr = w
+            .clone()
+            .permute::<Rank4<2, 3, 3, 3>, Axes4<1, 0, 2, 3>>()
+            .reshape::<Rank4<2, 3, 3, 3>>();
+
+        // stride 2 upsamples 4x4 back to 7x7
+        let y: Tensor<Rank3<2, 7, 7>, _, _, _> = r.leaky_trace().convtrans2d::<2, 1>(w_tr.clone());
+
+        // the gradient of conv2d wrt its input is convtrans2d of the output gradient
+        let g = (x.leaky_trace().conv2d::<2, 1>(w.clone()) * r.clone())
+            .sum()
+            .backward();
+        assert_close(&g.get(&x).array(), &y.array());
+
+        // and the other way around
+        let g = (y * x.clone()).sum().backward();
+        assert_close(&g.get(&r).array(), &x.conv2d::<2, 1>(w).array());
+    }
+
     #[test]
     fn test_batched_convtrans2d() {
         let dev: TestDevice = Default::default();
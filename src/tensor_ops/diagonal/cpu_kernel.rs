@@ -0,0 +1,51 @@
+use crate::{
+    shapes::{Dim, Dtype, Shape},
+    tensor::{Cpu, Tensor},
+};
+
+use std::sync::Arc;
+
+/// Index into `inp.data` of the `i`th element of the diagonal.
+fn diag_index<M: Dim, N: Dim, E: Dtype>(
+    offset: isize,
+    inp: &Tensor<(M, N), E, Cpu>,
+    i: usize,
+) -> usize {
+    let (row, col) = if offset >= 0 {
+        (i, i + offset as usize)
+    } else {
+        (i + offset.unsigned_abs(), i)
+    };
+    row * inp.strides[0] + col * inp.strides[1]
+}
+
+impl<E: Dtype> super::DiagonalKernel<E> for Cpu {
+    fn forward<M: Dim, N: Dim, L: Dim>(
+        &self,
+        offset: isize,
+        inp: &Tensor<(M, N), E, Self>,
+        out: &mut Tensor<(L,), E, Self>,
+    ) -> Result<(), Self::Err> {
+        let stride = out.strides[0];
+        let buf = Arc::make_mut(&mut out.data);
+        for i in 0..out.shape.0.size() {
+            buf[i * stride] = inp.data[diag_index(offset, inp, i)];
+        }
+        Ok(())
+    }
+
+    fn backward<M: Dim, N: Dim, L: Dim>(
+        &self,
+        offset: isize,
+        inp: &Tensor<(M, N), E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out_shape: (L,),
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let stride = out_shape.strides()[0];
+        for i in 0..out_shape.0.size() {
+            grad_inp[diag_index(offset, inp, i)] += grad_out[i * stride];
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use super::{Device, SumTo};
+use crate::{shapes::*, tensor::*};
+
+mod cpu_kernel;
+
+pub trait DiagonalKernel<E: Dtype>: DeviceStorage {
+    fn forward<M: Dim, N: Dim, L: Dim>(
+        &self,
+        offset: isize,
+        inp: &Tensor<(M, N), E, Self>,
+        out: &mut Tensor<(L,), E, Self>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<M: Dim, N: Dim, L: Dim>(
+        &self,
+        offset: isize,
+        inp: &Tensor<(M, N), E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        out_shape: (L,),
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Extracts the main diagonal of a square matrix.
+///
+/// See [Tensor::diagonal_offset()] for other diagonals, or non-square matrices.
+///
+/// **Pytorch equivalent**: `torch.diagonal(t)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// assert_eq!(t.diagonal().array(), [1.0, 4.0]);
+/// ```
+pub fn diagonal<N: Dim, E: Dtype, D, T: Tape<E, D>>(
+    t: Tensor<(N, N), E, D, T>,
+) -> Tensor<(N,), E, D, T>
+where
+    D: DiagonalKernel<E> + ZerosTensor<E>,
+{
+    t.diagonal()
+}
+
+/// Sum of the main diagonal of a square matrix.
+///
+/// This is named `matrix_trace` so it doesn't conflict with [Trace::trace()].
+///
+/// **Pytorch equivalent**: `torch.trace(t)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// assert_eq!(t.matrix_trace().array(), 5.0);
+/// ```
+pub fn matrix_trace<N: Dim, E: Dtype, D, T: Tape<E, D>>(
+    t: Tensor<(N, N), E, D, T>,
+) -> Tensor<Rank0, E, D, T>
+where
+    D: Device<E> + DiagonalKernel<E>,
+{
+    t.matrix_trace()
+}
+
+impl<N: Dim, E: Dtype, D: DiagonalKernel<E> + ZerosTensor<E>, T: Tape<E, D>>
+    Tensor<(N, N), E, D, T>
+{
+    /// See [diagonal()]
+    pub fn diagonal(self) -> Tensor<(N,), E, D, T> {
+        self.try_diagonal().unwrap()
+    }
+
+    /// See [diagonal()]
+    pub fn try_diagonal(self) -> Result<Tensor<(N,), E, D, T>, D::Err> {
+        assert_eq!(self.shape.0.size(), self.shape.1.size());
+        let n = self.shape.0;
+        try_diagonal_op(self, 0, (n,))
+    }
+
+    /// See [matrix_trace()]
+    pub fn matrix_trace(self) -> Tensor<Rank0, E, D, T>
+    where
+        D: Device<E>,
+    {
+        self.try_matrix_trace().unwrap()
+    }
+
+    /// See [matrix_trace()]
+    pub fn try_matrix_trace(self) -> Result<Tensor<Rank0, E, D, T>, D::Err>
+    where
+        D: Device<E>,
+    {
+        self.try_diagonal()?.try_sum()
+    }
+}
+
+impl<M: Dim, N: Dim, E: Dtype, D: DiagonalKernel<E> + ZerosTensor<E>, T: Tape<E, D>>
+    Tensor<(M, N), E, D, T>
+{
+    /// Extracts the diagonal starting at `(0, offset)` if `offset >= 0`,
+    /// or `(-offset, 0)` if `offset < 0`. Works for non-square matrices.
+    ///
+    /// **Pytorch equivalent**: `torch.diagonal(t, offset)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// assert_eq!(t.clone().diagonal_offset(1).as_vec(), [2.0, 6.0]);
+    /// assert_eq!(t.diagonal_offset(-1).as_vec(), [4.0]);
+    /// ```
+    pub fn diagonal_offset(self, offset: isize) -> Tensor<(usize,), E, D, T> {
+        self.try_diagonal_offset(offset).unwrap()
+    }
+
+    /// See [Tensor::diagonal_offset()]
+    pub fn try_diagonal_offset(self, offset: isize) -> Result<Tensor<(usize,), E, D, T>, D::Err> {
+        let (m, n) = (self.shape.0.size(), self.shape.1.size());
+        let len = if offset >= 0 {
+            m.min(n.saturating_sub(offset as usize))
+        } else {
+            n.min(m.saturating_sub(offset.unsigned_abs()))
+        };
+        try_diagonal_op(self, offset, (len,))
+    }
+}
+
+fn try_diagonal_op<M: Dim, N: Dim, L: Dim, E: Dtype, D, T: Tape<E, D>>(
+    t: Tensor<(M, N), E, D, T>,
+    offset: isize,
+    out_shape: (L,),
+) -> Result<Tensor<(L,), E, D, T>, D::Err>
+where
+    D: DiagonalKernel<E> + ZerosTensor<E>,
+{
+    let (inp, mut tape) = t.split_tape();
+    let mut out = inp.device.try_zeros_like(&out_shape)?;
+    inp.device.forward(offset, &inp, &mut out)?;
+    let inp_ghost = inp.ghost();
+    let out_ghost = out.ghost();
+    tape.add_backward_op(move |grads| {
+        grads.try_alloc_for(&inp_ghost)?;
+        grads.try_alloc_for(&out_ghost)?;
+        let (grad_inp, grad_out) = grads.mut_and_ref(&inp_ghost, &out_ghost);
+        inp.device
+            .backward(offset, &inp, grad_inp, out_shape, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_diagonal_and_trace() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let d = t.leaky_trace().diagonal();
+        assert_eq!(d.array(), [1.0, 5.0, 9.0]);
+        let g = d.exp().sum().backward();
+        let e = t.clone().exp().array();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [e[0][0], 0.0, 0.0],
+                [0.0, e[1][1], 0.0],
+                [0.0, 0.0, e[2][2]],
+            ],
+        );
+
+        let r = t.leaky_trace().matrix_trace();
+        assert_eq!(r.array(), 15.0);
+        let g = r.backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn test_diagonal_offset() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<3, 4>, TestDtype, _> = dev.tensor([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+        ]);
+        assert_eq!(t.clone().diagonal_offset(0).as_vec(), [1.0, 6.0, 11.0]);
+        assert_eq!(t.clone().diagonal_offset(2).as_vec(), [3.0, 8.0]);
+        assert_eq!(t.clone().diagonal_offset(-2).as_vec(), [9.0]);
+        assert_eq!(t.clone().diagonal_offset(5).shape(), &(0,));
+
+        // transposed input
+        let r = t
+            .leaky_trace()
+            .permute::<Rank2<4, 3>, _>()
+            .diagonal_offset(-1);
+        assert_eq!(r.as_vec(), [2.0, 7.0, 12.0]);
+        let g = r.sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0]
+            ]
+        );
+    }
+}
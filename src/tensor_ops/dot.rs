@@ -0,0 +1,115 @@
+#![allow(clippy::type_complexity)]
+
+use super::*;
+use crate::{shapes::*, tensor::*};
+
+/// Dot product of two vectors, `sum(a * b)`.
+///
+/// **Pytorch equivalent**: `torch.dot(a, b)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, 2.0, 3.0]);
+/// let b = dev.tensor([4.0, 5.0, 6.0]);
+/// assert_eq!(dot(a, b).array(), 32.0);
+/// ```
+pub fn dot<N: Dim, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<R>, R: Tape<E, D>>(
+    a: Tensor<(N,), E, D, T>,
+    b: Tensor<(N,), E, D, R>,
+) -> Tensor<Rank0, E, D, T> {
+    a.dot(b)
+}
+
+/// Outer product of two vectors, `out[i][j] = a[i] * b[j]`.
+///
+/// This is the same as [matmul()] of two vectors.
+///
+/// **Pytorch equivalent**: `torch.outer(a, b)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, 2.0]);
+/// let b = dev.tensor([3.0, 4.0, 5.0]);
+/// assert_eq!(outer(a, b).array(), [[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+/// ```
+pub fn outer<M: Dim, N: Dim, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<R>, R: Tape<E, D>>(
+    a: Tensor<(M,), E, D, T>,
+    b: Tensor<(N,), E, D, R>,
+) -> Tensor<(M, N), E, D, T> {
+    a.outer(b)
+}
+
+impl<M: Dim, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<(M,), E, D, T> {
+    /// See [dot()]
+    pub fn dot<R: Tape<E, D>>(self, b: Tensor<(M,), E, D, R>) -> Tensor<Rank0, E, D, T>
+    where
+        T: Merge<R>,
+    {
+        self.try_dot(b).unwrap()
+    }
+
+    /// See [dot()]
+    pub fn try_dot<R: Tape<E, D>>(
+        self,
+        b: Tensor<(M,), E, D, R>,
+    ) -> Result<Tensor<Rank0, E, D, T>, D::Err>
+    where
+        T: Merge<R>,
+    {
+        self.try_mul(b)?.try_sum()
+    }
+
+    /// See [outer()]
+    pub fn outer<N: Dim, R: Tape<E, D>>(self, b: Tensor<(N,), E, D, R>) -> Tensor<(M, N), E, D, T>
+    where
+        T: Merge<R>,
+    {
+        self.try_outer(b).unwrap()
+    }
+
+    /// See [outer()]
+    pub fn try_outer<N: Dim, R: Tape<E, D>>(
+        self,
+        b: Tensor<(N,), E, D, R>,
+    ) -> Result<Tensor<(M, N), E, D, T>, D::Err>
+    where
+        T: Merge<R>,
+    {
+        self.try_matmul(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_dot() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([1.0, -2.0, 0.5]);
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([3.0, 1.0, 4.0]);
+        let r = a.leaky_trace().dot(b.leaky_trace());
+        assert_close(&r.array(), &3.0);
+
+        let g = r.backward();
+        assert_close(&g.get(&a).array(), &b.array());
+        assert_close(&g.get(&b).array(), &a.array());
+    }
+
+    #[test]
+    fn test_outer() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([1.0, -2.0]);
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.tensor([3.0, 1.0, 4.0]);
+        let r = a.leaky_trace().outer(b.leaky_trace());
+        assert_close(&r.array(), &[[3.0, 1.0, 4.0], [-6.0, -2.0, -8.0]]);
+
+        // d/da sum(a_i * b_j) = sum(b), and vice versa
+        let g = r.sum().backward();
+        assert_close(&g.get(&a).array(), &[8.0; 2]);
+        assert_close(&g.get(&b).array(), &[-1.0; 3]);
+    }
+}
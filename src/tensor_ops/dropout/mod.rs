@@ -5,9 +5,11 @@ mod cuda_kernel;
 
 use crate::{
     shapes::*,
-    tensor::{DeviceStorage, PutTape, SplitTape, Tape, Tensor},
+    tensor::{DeviceStorage, Merge, NoneTape, PutTape, SplitTape, Tape, Tensor},
 };
 
+use super::{ChooseFrom, Device, TryMul};
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct DropoutKernelOp<F> {
@@ -49,6 +51,9 @@ pub trait DropoutKernel<E: Dtype>: DeviceStorage {
 /// and then instantiates two identical [rand::rngs::StdRng] with that seed. These rngs
 /// are used in both the forward pass and backward pass to generate identical
 /// random numbers, so the masking is the same for both.
+///
+/// Every device draws these random numbers on the host, in the order of the tensor's
+/// underlying buffer, so devices seeded with the same value drop the same elements.
 pub fn dropout<S: Shape, E: Dtype, D: DropoutKernel<E>, T: Tape<E, D>>(
     t: Tensor<S, E, D, T>,
     prob: E,
@@ -79,10 +84,62 @@ impl<S: Shape, E: Dtype, D: DropoutKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T>
     }
 }
 
+/// Same as [dropout()], but uses a provided `mask` instead of sampling one. Elements where
+/// `mask` is `false` are zeroed, and the others are scaled by `1 / (1 - p)`.
+///
+/// This makes it possible to apply the exact same dropout to multiple tensors.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let mask = dev.tensor([true, false, true, false]);
+/// let a = dev.tensor([1.0f32, 2.0, 3.0, 4.0]);
+/// let b = dev.tensor([-1.0f32, -2.0, -3.0, -4.0]);
+/// assert_eq!(a.dropout_with_mask(&mask, 0.5).array(), [2.0, 0.0, 6.0, 0.0]);
+/// assert_eq!(b.dropout_with_mask(&mask, 0.5).array(), [-2.0, 0.0, -6.0, 0.0]);
+/// ```
+pub fn dropout_with_mask<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<NoneTape>>(
+    t: Tensor<S, E, D, T>,
+    mask: &Tensor<S, bool, D>,
+    prob: E,
+) -> Tensor<S, E, D, T> {
+    t.dropout_with_mask(mask, prob)
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<NoneTape>> Tensor<S, E, D, T> {
+    /// See [dropout_with_mask]
+    pub fn dropout_with_mask(self, mask: &Tensor<S, bool, D>, prob: E) -> Self {
+        self.try_dropout_with_mask(mask, prob).unwrap()
+    }
+    /// See [dropout_with_mask]
+    pub fn try_dropout_with_mask(self, mask: &Tensor<S, bool, D>, prob: E) -> Result<Self, D::Err> {
+        assert_eq!(self.shape, mask.shape);
+        let zeros = self.device.try_zeros_like(&self.shape)?;
+        let scaled = self.try_mul(E::ONE / (E::ONE - prob))?;
+        mask.clone().try_choose(scaled, zeros)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{tensor::*, tensor_ops::*, tests::*};
 
+    #[cfg(feature = "cuda")]
+    #[test]
+    fn test_dropout_same_mask_on_cpu_and_cuda() {
+        use crate::shapes::Rank2;
+        let cpu = Cpu::seed_from_u64(13);
+        let cuda = Cuda::seed_from_u64(13);
+        let x_cpu: Tensor<Rank2<8, 16>, f32, _> = cpu.ones();
+        let x_cuda: Tensor<Rank2<8, 16>, f32, _> = cuda.ones();
+        for _ in 0..3 {
+            let r_cpu = x_cpu.clone().dropout(0.5).as_vec();
+            let r_cuda = x_cuda.clone().dropout(0.5).as_vec();
+            assert_eq!(r_cpu, r_cuda);
+        }
+    }
+
     #[test]
     fn test_dropout_all_0d() {
         let dev: TestDevice = Default::default();
@@ -126,4 +183,20 @@ mod tests {
             &[[0.47214523, 0.5350107, 0.2527211], [0.0, 0.0, 1.4543099]],
         );
     }
+
+    #[test]
+    fn test_dropout_with_same_mask() {
+        let dev: TestDevice = Default::default();
+        let mask = dev.tensor([[true, false, true], [false, false, true]]);
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[0.05, 0.1, -0.2], [0.3, -0.4, 0.5]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let ra = a.leaky_trace().dropout_with_mask(&mask, 0.5);
+        let rb = b.dropout_with_mask(&mask, 0.5);
+        assert_close(&ra.array(), &[[0.1, 0.0, -0.4], [0.0, 0.0, 1.0]]);
+        assert_close(&rb.array(), &[[2.0, 0.0, 6.0], [0.0, 0.0, 12.0]]);
+
+        let g = ra.sum().backward();
+        assert_close(&g.get(&a).array(), &[[2.0, 0.0, 2.0], [0.0, 0.0, 2.0]]);
+    }
 }
@@ -0,0 +1,47 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::EluKernelOp<F> {
+    const DF_USES_FX: bool = false;
+    const HAS_CONST_DF: bool = false;
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        if x > F::zero() {
+            x
+        } else {
+            self.alpha * x.exp_m1()
+        }
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        if x > F::zero() {
+            F::one()
+        } else {
+            self.alpha * x.exp()
+        }
+    }
+}
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::SeluKernelOp {
+    const DF_USES_FX: bool = false;
+    const HAS_CONST_DF: bool = false;
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let scale = F::from(1.0507009873554805).unwrap();
+        let alpha = F::from(1.6732632423543772).unwrap();
+        if x > F::zero() {
+            scale * x
+        } else {
+            scale * alpha * x.exp_m1()
+        }
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let scale = F::from(1.0507009873554805).unwrap();
+        let alpha = F::from(1.6732632423543772).unwrap();
+        if x > F::zero() {
+            scale
+        } else {
+            scale * alpha * x.exp()
+        }
+    }
+}
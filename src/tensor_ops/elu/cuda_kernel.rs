@@ -0,0 +1,13 @@
+use super::{EluKernelOp as Elu, SeluKernelOp as Selu};
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::DeviceRepr for Elu<f32> {}
+unsafe impl cudarc::driver::DeviceRepr for Elu<f64> {}
+unsafe impl cudarc::driver::DeviceRepr for Selu {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/elu.ptx"));
+
+cuda_unary!(Elu<f32>, f32, PTX, "elu_fwd_f32", "elu_bwd_f32");
+cuda_unary!(Elu<f64>, f64, PTX, "elu_fwd_f64", "elu_bwd_f64");
+cuda_unary!(Selu, f32, PTX, "selu_fwd_f32", "selu_bwd_f32");
+cuda_unary!(Selu, f64, PTX, "selu_fwd_f64", "selu_bwd_f64");
@@ -0,0 +1,27 @@
+#include "unary_op_macros.cuh"
+
+#define SELU_ALPHA 1.6732632423543772
+#define SELU_SCALE 1.0507009873554805
+
+template<typename F>
+struct EluKernelOp {
+    F alpha;
+};
+
+struct SeluKernelOp {};
+
+UNARY_OP(float, elu_fwd_f32, elu_bwd_f32, EluKernelOp<float>,
+    x > 0.0 ? x : op.alpha * expm1f(x),
+    x > 0.0 ? 1.0 : op.alpha * expf(x))
+
+UNARY_OP(double, elu_fwd_f64, elu_bwd_f64, EluKernelOp<double>,
+    x > 0.0 ? x : op.alpha * expm1(x),
+    x > 0.0 ? 1.0 : op.alpha * exp(x))
+
+UNARY_OP(float, selu_fwd_f32, selu_bwd_f32, SeluKernelOp,
+    x > 0.0 ? SELU_SCALE * x : SELU_SCALE * SELU_ALPHA * expm1f(x),
+    x > 0.0 ? SELU_SCALE : SELU_SCALE * SELU_ALPHA * expf(x))
+
+UNARY_OP(double, selu_fwd_f64, selu_bwd_f64, SeluKernelOp,
+    x > 0.0 ? SELU_SCALE * x : SELU_SCALE * SELU_ALPHA * expm1(x),
+    x > 0.0 ? SELU_SCALE : SELU_SCALE * SELU_ALPHA * exp(x))
@@ -0,0 +1,119 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct EluKernelOp<E> {
+    pub alpha: E,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SeluKernelOp;
+
+/// [Exponential Linear Unit (ELU)](https://pytorch.org/docs/stable/generated/torch.nn.ELU.html).
+/// `t` if `t > 0`, otherwise `alpha * (exp(t) - 1)`.
+///
+/// The derivative is `1` for `t > 0`, otherwise `alpha * exp(t)`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.elu(1.0);
+/// ```
+pub fn elu<S: Shape, E: Dtype, D: UnaryKernel<EluKernelOp<E>, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    alpha: E,
+) -> Tensor<S, E, D, T> {
+    t.elu(alpha)
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<EluKernelOp<E>, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [elu]
+    pub fn elu(self, alpha: E) -> Self {
+        self.try_elu(alpha).unwrap()
+    }
+    /// See [elu]
+    pub fn try_elu(self, alpha: E) -> Result<Self, D::Err> {
+        try_unary_op(EluKernelOp { alpha }, self)
+    }
+}
+
+/// [Scaled Exponential Linear Unit (SELU)](https://pytorch.org/docs/stable/generated/torch.nn.SELU.html).
+/// `scale * elu(t, alpha)`, with the fixed constants `alpha = 1.6732632423543772`
+/// and `scale = 1.0507009873554805` from [Self-Normalizing Neural Networks](https://arxiv.org/abs/1706.02515).
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.selu();
+/// ```
+pub fn selu<S: Shape, E: Dtype, D: UnaryKernel<SeluKernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.selu()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<SeluKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [selu]
+    pub fn selu(self) -> Self {
+        self.try_selu().unwrap()
+    }
+    /// See [selu]
+    pub fn try_selu(self) -> Result<Self, D::Err> {
+        try_unary_op(SeluKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_elu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.leaky_trace().elu(0.5);
+        assert_close(&r.array(), &[-0.43233235, -0.31606028, 0.0, 1.0, 2.0]);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.013533528, 0.036787944, 0.1, 0.2, 0.2],
+        );
+    }
+
+    #[test]
+    fn test_selu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.leaky_trace().selu();
+        assert_close(
+            &r.array(),
+            &[-1.5201665, -1.1113307, 0.0, 1.050701, 2.101402],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.047586574, 0.12935372, 0.35161987, 0.2101402, 0.2101402],
+        );
+    }
+
+    #[test]
+    fn test_elu_selu_finite_differences() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal::<Rank1<8>>() * 2.0;
+        let err = check_gradient(|x| x.elu(0.7).sum(), &x, 1e-3);
+        assert!(err < 1e-2, "{err}");
+        let err = check_gradient(|x| x.selu().sum(), &x, 1e-3);
+        assert!(err < 1e-2, "{err}");
+    }
+}
@@ -3,9 +3,14 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use super::ops::{try_unary_op, UnaryKernel};
+use super::{
+    clamp::ClampKernelOp,
+    ops::{try_unary_op, UnaryKernel},
+};
 use crate::{shapes::*, tensor::*};
 
+use num_traits::Float;
+
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct ExpKernelOp;
@@ -38,6 +43,43 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<ExpKernelOp, E>, T: Tape<E, D>> Tensor<S
     }
 }
 
+/// [exp], but with the input clamped to at most `max_input` first, so that the output
+/// stays finite. The gradient is zero where the input was clamped.
+///
+/// `max_input` should be at most `ln(E::MAX)`, e.g. `88.0` for `f32` or `709.0` for `f64`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([0.0f32, 1000.0]);
+/// let r = t.exp_clamped(88.0);
+/// assert_eq!(r.array(), [1.0, 88.0f32.exp()]);
+/// ```
+pub fn exp_clamped<S: Shape, E: Dtype + Float, D, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    max_input: E,
+) -> Tensor<S, E, D, T>
+where
+    D: UnaryKernel<ExpKernelOp, E> + UnaryKernel<ClampKernelOp<E>, E>,
+{
+    t.exp_clamped(max_input)
+}
+
+impl<S: Shape, E: Dtype + Float, D, T: Tape<E, D>> Tensor<S, E, D, T>
+where
+    D: UnaryKernel<ExpKernelOp, E> + UnaryKernel<ClampKernelOp<E>, E>,
+{
+    /// See [exp_clamped]
+    pub fn exp_clamped(self, max_input: E) -> Self {
+        self.try_exp_clamped(max_input).unwrap()
+    }
+    /// See [exp_clamped]
+    pub fn try_exp_clamped(self, max_input: E) -> Result<Self, D::Err> {
+        self.try_clamp(E::neg_infinity(), max_input)?.try_exp()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{tensor::*, tensor_ops::*, tests::*};
@@ -57,4 +99,51 @@ mod tests {
             &[0.027067056, 0.07357589, 0.2, 0.54365635, 1.4778112],
         );
     }
+
+    #[test]
+    fn test_exp_clamped() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1.0, 0.0, 1.0, 80.0, 1000.0]);
+        let r = x.leaky_trace().exp_clamped(80.0);
+        let r_arr = r.array();
+        assert!(r_arr.iter().all(|v| v.is_finite()));
+        assert_eq!(r_arr[4], r_arr[3]);
+        let e = x.clone().exp().array();
+        for i in 0..3 {
+            assert_close(&r_arr[i], &e[i]);
+        }
+
+        let g = r.sum().backward();
+        let g = g.get(&x).array();
+        assert_eq!(g[4], 0.0);
+        for i in 0..4 {
+            assert_close(&g[i], &e[i]);
+        }
+    }
+
+    #[cfg(feature = "cuda")]
+    #[test]
+    fn test_exp_f64_cuda_matches_cpu() {
+        use crate::shapes::Rank1;
+        let cpu: Cpu = Default::default();
+        let cuda: Cuda = Default::default();
+        let data: Vec<f64> = (0..64).map(|i| -700.0 + 11.0 * i as f64).collect();
+
+        let x_cpu: Tensor<Rank1<64>, f64, _> = cpu.tensor(data.clone());
+        let x_cuda: Tensor<Rank1<64>, f64, _> = cuda.tensor(data);
+        let y_cpu = x_cpu.clone().exp();
+        let y_cuda = x_cuda.clone().exp();
+        let g_cpu = x_cpu.leaky_trace().exp().sum().backward();
+        let g_cuda = x_cuda.leaky_trace().exp().sum().backward();
+
+        let pairs = [
+            (y_cpu.as_vec(), y_cuda.as_vec()),
+            (g_cpu.get(&x_cpu).as_vec(), g_cuda.get(&x_cuda).as_vec()),
+        ];
+        for (a, b) in pairs {
+            for (a, b) in a.into_iter().zip(b) {
+                assert!((a - b).abs() <= 4.0 * f64::EPSILON * a.abs(), "{a} != {b}");
+            }
+        }
+    }
 }
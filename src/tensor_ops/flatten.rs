@@ -0,0 +1,135 @@
+use super::reshape_to::{ReshapeKernel, ReshapeTo};
+use crate::{shapes::*, tensor::*};
+
+/// Flattens all dimensions from axis `Ax` onwards into a single [usize] dimension,
+/// keeping the dimensions before `Ax`. Useful for connecting convolutional layers
+/// to linear layers. See [Tensor::unflatten()] for the inverse.
+///
+/// If the tensor is contiguous, then no data movement will occur.
+///
+/// **Pytorch equivalent**: `t.flatten(start_dim=Ax)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank4<2, 3, 4, 4>, f32, _> = dev.zeros();
+/// let r = t.flatten_from_axis::<Axis<1>>();
+/// assert_eq!(r.shape(), &(Const::<2>, 48));
+/// ```
+pub fn flatten_from_axis<Ax: Axes<Array = [isize; 1]>, S, E: Dtype, D, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S::Flattened, E, D, T>
+where
+    S: FlattenShape<Ax>,
+    D: ReshapeKernel<E>,
+{
+    t.flatten_from_axis::<Ax>()
+}
+
+impl<S: Shape, E: Dtype, D: ReshapeKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [flatten_from_axis()]
+    pub fn flatten_from_axis<Ax: Axes<Array = [isize; 1]>>(self) -> Tensor<S::Flattened, E, D, T>
+    where
+        S: FlattenShape<Ax>,
+    {
+        self.try_flatten_from_axis::<Ax>().unwrap()
+    }
+
+    /// See [flatten_from_axis()]
+    pub fn try_flatten_from_axis<Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Tensor<S::Flattened, E, D, T>, D::Err>
+    where
+        S: FlattenShape<Ax>,
+    {
+        let dst = self.shape.flattened();
+        self.try_reshape_like(&dst).unwrap()
+    }
+
+    /// Inverse of [flatten_from_axis()]. Splits the last dimension back into the
+    /// dimensions of `dst` from axis `Ax` onwards.
+    ///
+    /// Panics if `dst` doesn't flatten to the shape of `self`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<(Const<2>, usize), f32, _> = dev.zeros_like(&(Const, 48));
+    /// let r = t.unflatten::<_, Axis<1>>(&Rank4::<2, 3, 4, 4>::default());
+    /// assert_eq!(r.shape(), &(Const::<2>, Const::<3>, Const::<4>, Const::<4>));
+    /// ```
+    pub fn unflatten<Dst, Ax: Axes<Array = [isize; 1]>>(self, dst: &Dst) -> Tensor<Dst, E, D, T>
+    where
+        Dst: FlattenShape<Ax, Flattened = S>,
+    {
+        self.try_unflatten::<Dst, Ax>(dst).unwrap()
+    }
+
+    /// See [Tensor::unflatten()]
+    pub fn try_unflatten<Dst, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        dst: &Dst,
+    ) -> Result<Tensor<Dst, E, D, T>, D::Err>
+    where
+        Dst: FlattenShape<Ax, Flattened = S>,
+    {
+        assert_eq!(
+            dst.flattened().concrete().as_ref(),
+            self.shape.concrete().as_ref(),
+            "Unflattened shape {dst:?} does not flatten to {:?}",
+            self.shape
+        );
+        self.try_reshape_like(dst).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_flatten_from_axis() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 4, 4>, TestDtype, _> = dev.sample_normal();
+        let r = x.leaky_trace().flatten_from_axis::<Axis<1>>();
+        assert_eq!(r.shape(), &(Const::<2>, 48));
+        assert_eq!(r.as_vec(), x.as_vec());
+        let r = r.realize::<Rank2<2, 48>>().unwrap();
+
+        let g = r.exp().sum().backward();
+        let g = g.get(&x);
+        assert_eq!(g.shape(), x.shape());
+        assert_close(&g.array(), &x.clone().exp().array());
+    }
+
+    #[test]
+    fn test_flatten_all_axes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = x.clone().flatten_from_axis::<Axis<0>>();
+        assert_eq!(r.shape(), &(24,));
+        assert_eq!(r.as_vec(), x.as_vec());
+    }
+
+    #[test]
+    fn test_unflatten_round_trip() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 4, 4>, TestDtype, _> = dev.sample_normal();
+        let r = x.leaky_trace().flatten_from_axis::<Axis<2>>();
+        assert_eq!(r.shape(), &(Const::<2>, Const::<3>, 16));
+        let r = r.unflatten::<_, Axis<2>>(x.shape());
+        assert_eq!(r.array(), x.array());
+
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&x).array(), &x.clone().exp().array());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unflatten_wrong_shape() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<(Const<2>, usize), TestDtype, _> = dev.zeros_like(&(Const, 47));
+        let _ = x.unflatten::<_, Axis<1>>(&Rank4::<2, 3, 4, 4>::default());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{
+        cpu::{index_to_i, NdIndex},
+        Cpu, Tensor,
+    },
+};
+
+use std::sync::Arc;
+
+fn strided_index<S: Shape>(idx: S::Concrete, strides: S::Concrete) -> usize {
+    idx.into_iter().zip(strides).map(|(i, s)| i * s).sum()
+}
+
+impl<E: Dtype> super::GatherDimKernel<E> for Cpu {
+    fn forward<S: Shape, Idx: Shape<Concrete = S::Concrete>>(
+        &self,
+        axis: usize,
+        inp: &Tensor<S, E, Self>,
+        idx: &Tensor<Idx, usize, Self>,
+        out: &mut Tensor<Idx, E, Self>,
+    ) -> Result<(), Self::Err> {
+        if idx.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let out_strides = out.strides;
+        let buf = Arc::make_mut(&mut out.data);
+        let mut idx_iter = NdIndex::new(idx.shape, idx.strides);
+        while let Some((i, mut pos)) = idx_iter.next_with_idx() {
+            let o = strided_index::<S>(pos, out_strides);
+            pos[axis] = idx.data[i];
+            buf[o] = inp.data[index_to_i(&inp.shape, &inp.strides, pos)];
+        }
+        Ok(())
+    }
+
+    fn backward<S: Shape, Idx: Shape<Concrete = S::Concrete>>(
+        &self,
+        axis: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<Idx, usize, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        if idx.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let out_strides = idx.shape.strides();
+        let mut idx_iter = NdIndex::new(idx.shape, idx.strides);
+        while let Some((i, mut pos)) = idx_iter.next_with_idx() {
+            let o = strided_index::<S>(pos, out_strides);
+            pos[axis] = idx.data[i];
+            grad_inp[index_to_i(&inp.shape, &inp.strides, pos)] += grad_out[o];
+        }
+        Ok(())
+    }
+}
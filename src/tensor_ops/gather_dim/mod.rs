@@ -0,0 +1,161 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{shapes::*, tensor::*};
+
+mod cpu_kernel;
+
+pub trait GatherDimKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape, Idx: Shape<Concrete = S::Concrete>>(
+        &self,
+        axis: usize,
+        inp: &Tensor<S, E, Self>,
+        idx: &Tensor<Idx, usize, Self>,
+        out: &mut Tensor<Idx, E, Self>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<S: Shape, Idx: Shape<Concrete = S::Concrete>>(
+        &self,
+        axis: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        idx: &Tensor<Idx, usize, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Selects values along axis `Ax` using an index tensor of the same rank. The output has
+/// the shape of `idx`, with `out[i][j] = t[idx[i][j]][j]` for `Axis<0>` and
+/// `out[i][j] = t[i][idx[i][j]]` for `Axis<1>`.
+///
+/// Along every other axis, `idx` must not be larger than `t`.
+/// Gradients are added to the positions that were gathered from.
+///
+/// **Pytorch equivalent**: `torch.gather(t, Ax, idx)`
+///
+/// Selecting one element from each row:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let idx = dev.tensor([[2], [0]]);
+/// let r: Tensor<Rank2<2, 1>, f32, _> = t.gather_dim::<Axis<1>, _>(idx);
+/// assert_eq!(r.array(), [[3.0], [4.0]]);
+/// ```
+pub fn gather_dim<Ax: Axes<Array = [isize; 1]>, S: Shape, Idx, E: Dtype, D, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    idx: Tensor<Idx, usize, D>,
+) -> Tensor<Idx, E, D, T>
+where
+    Idx: Shape<Concrete = S::Concrete>,
+    D: GatherDimKernel<E> + ZerosTensor<E>,
+{
+    t.gather_dim::<Ax, Idx>(idx)
+}
+
+impl<S: Shape, E: Dtype, D: GatherDimKernel<E> + ZerosTensor<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [gather_dim()]
+    pub fn gather_dim<Ax: Axes<Array = [isize; 1]>, Idx: Shape<Concrete = S::Concrete>>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Tensor<Idx, E, D, T> {
+        self.try_gather_dim::<Ax, Idx>(idx).unwrap()
+    }
+
+    /// See [gather_dim()]
+    pub fn try_gather_dim<Ax: Axes<Array = [isize; 1]>, Idx: Shape<Concrete = S::Concrete>>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Result<Tensor<Idx, E, D, T>, D::Err> {
+        let axis = Ax::as_array()[0] as usize;
+        let src_dims = self.shape.concrete();
+        let idx_dims = idx.shape.concrete();
+        for i in 0..S::NUM_DIMS {
+            if i != axis {
+                assert!(
+                    idx_dims[i] <= src_dims[i],
+                    "gather_dim index shape {idx_dims:?} is larger than the source shape {src_dims:?} along axis {i}"
+                );
+            }
+        }
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&idx.shape)?;
+        inp.device.forward(axis, &inp, &idx, &mut out)?;
+        let inp_ghost = inp.ghost();
+        let out_ghost = out.ghost();
+        tape.add_backward_op(move |grads| {
+            grads.try_alloc_for(&inp_ghost)?;
+            grads.try_alloc_for(&out_ghost)?;
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp_ghost, &out_ghost);
+            inp.device.backward(axis, &inp, grad_inp, &idx, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_gather_dim_one_per_row() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+        let idx: Tensor<Rank2<3, 1>, usize, _> = dev.tensor([[3], [0], [3]]);
+
+        let r = t.leaky_trace().gather_dim::<Axis<1>, _>(idx);
+        let t_arr = t.array();
+        assert_eq!(r.array(), [[t_arr[0][3]], [t_arr[1][0]], [t_arr[2][3]]]);
+
+        let g = r.exp().sum().backward();
+        let mut expected = [[0.0; 4]; 3];
+        expected[0][3] = t_arr[0][3].exp();
+        expected[1][0] = t_arr[1][0].exp();
+        expected[2][3] = t_arr[2][3].exp();
+        assert_close(&g.get(&t).array(), &expected);
+    }
+
+    #[test]
+    fn test_gather_dim_first_axis_repeated() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let idx: Tensor<Rank2<3, 2>, usize, _> = dev.tensor([[0, 1], [1, 1], [1, 0]]);
+
+        let r = t.leaky_trace().gather_dim::<Axis<0>, _>(idx);
+        assert_eq!(r.array(), [[1.0, 5.0], [4.0, 5.0], [4.0, 2.0]]);
+
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 1.0, 0.0], [2.0, 2.0, 0.0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gather_dim_index_too_large() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let idx: Tensor<Rank2<3, 1>, usize, _> = dev.zeros();
+        let _ = t.gather_dim::<Axis<1>, _>(idx);
+    }
+
+    #[test]
+    #[should_panic = "Index out of bounds: index=[1, 3]"]
+    fn test_gather_dim_index_out_of_bounds() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let idx: Tensor<Rank2<2, 1>, usize, _> = dev.tensor([[0], [3]]);
+        let _ = t.gather_dim::<Axis<1>, _>(idx);
+    }
+
+    #[test]
+    fn test_gather_dim_zero_size_index() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let idx: Tensor<(Const<2>, usize), usize, _> = dev.zeros_like(&(Const, 0));
+        let r = t.clone().gather_dim::<Axis<1>, _>(idx.clone());
+        assert_eq!(r.shape(), &(Const::<2>, 0));
+
+        let mut grad_inp = std::vec![0.0; 6];
+        GatherDimKernel::backward(&dev, 1, &t, &mut grad_inp, &idx, &std::vec::Vec::new()).unwrap();
+        assert_eq!(grad_inp, [0.0; 6]);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::HardSigmoidKernelOp {
+    const DF_USES_FX: bool = false;
+    const HAS_CONST_DF: bool = false;
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let three = F::from(3.0).unwrap();
+        let six = F::from(6.0).unwrap();
+        ((x + three) / six).max(F::zero()).min(F::one())
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let three = F::from(3.0).unwrap();
+        if -three < x && x < three {
+            F::from(6.0).unwrap().recip()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::HardSwishKernelOp {
+    const DF_USES_FX: bool = false;
+    const HAS_CONST_DF: bool = false;
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let three = F::from(3.0).unwrap();
+        let six = F::from(6.0).unwrap();
+        x * ((x + three) / six).max(F::zero()).min(F::one())
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let three = F::from(3.0).unwrap();
+        if x < -three {
+            F::zero()
+        } else if x <= three {
+            x / three + F::from(0.5).unwrap()
+        } else {
+            F::one()
+        }
+    }
+}
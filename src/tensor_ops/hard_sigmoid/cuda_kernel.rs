@@ -0,0 +1,36 @@
+use super::{HardSigmoidKernelOp as HardSigmoid, HardSwishKernelOp as HardSwish};
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::DeviceRepr for HardSigmoid {}
+unsafe impl cudarc::driver::DeviceRepr for HardSwish {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/hard_sigmoid.ptx"));
+
+cuda_unary!(
+    HardSigmoid,
+    f32,
+    PTX,
+    "hard_sigmoid_fwd_f32",
+    "hard_sigmoid_bwd_f32"
+);
+cuda_unary!(
+    HardSigmoid,
+    f64,
+    PTX,
+    "hard_sigmoid_fwd_f64",
+    "hard_sigmoid_bwd_f64"
+);
+cuda_unary!(
+    HardSwish,
+    f32,
+    PTX,
+    "hard_swish_fwd_f32",
+    "hard_swish_bwd_f32"
+);
+cuda_unary!(
+    HardSwish,
+    f64,
+    PTX,
+    "hard_swish_fwd_f64",
+    "hard_swish_bwd_f64"
+);
@@ -0,0 +1,36 @@
+#include "unary_op_macros.cuh"
+#include "cuda_utils.cuh"
+
+struct HardSigmoidKernelOp {};
+struct HardSwishKernelOp {};
+
+template<typename T>
+__device__ T hard_sigmoid(T x) {
+    return ming(maxg((x + (T)3.0) / (T)6.0, (T)0.0), (T)1.0);
+}
+
+template<typename T>
+__device__ T hard_sigmoid_bwd(T x) {
+    return ((T)-3.0 < x && x < (T)3.0) ? (T)1.0 / (T)6.0 : (T)0.0;
+}
+
+template<typename T>
+__device__ T hard_swish_bwd(T x) {
+    return x < (T)-3.0 ? (T)0.0 : (x <= (T)3.0 ? x / (T)3.0 + (T)0.5 : (T)1.0);
+}
+
+UNARY_OP(float, hard_sigmoid_fwd_f32, hard_sigmoid_bwd_f32, HardSigmoidKernelOp,
+    hard_sigmoid(x),
+    hard_sigmoid_bwd(x))
+
+UNARY_OP(double, hard_sigmoid_fwd_f64, hard_sigmoid_bwd_f64, HardSigmoidKernelOp,
+    hard_sigmoid(x),
+    hard_sigmoid_bwd(x))
+
+UNARY_OP(float, hard_swish_fwd_f32, hard_swish_bwd_f32, HardSwishKernelOp,
+    x * hard_sigmoid(x),
+    hard_swish_bwd(x))
+
+UNARY_OP(double, hard_swish_fwd_f64, hard_swish_bwd_f64, HardSwishKernelOp,
+    x * hard_sigmoid(x),
+    hard_swish_bwd(x))
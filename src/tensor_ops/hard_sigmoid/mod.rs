@@ -0,0 +1,100 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct HardSigmoidKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct HardSwishKernelOp;
+
+/// [Hard Sigmoid](https://pytorch.org/docs/stable/generated/torch.nn.Hardsigmoid.html). `clamp((t + 3) / 6, 0, 1)`.
+///
+/// A piecewise linear approximation of [crate::tensor_ops::sigmoid()]. The derivative is `1 / 6`
+/// for `-3 < t < 3`, and `0` otherwise.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-3.0, 0.0, 3.0]);
+/// let r = t.hard_sigmoid();
+/// assert_eq!(r.array(), [0.0, 0.5, 1.0]);
+/// ```
+pub fn hard_sigmoid<S: Shape, E: Dtype, D: UnaryKernel<HardSigmoidKernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.hard_sigmoid()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<HardSigmoidKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [hard_sigmoid]
+    pub fn hard_sigmoid(self) -> Self {
+        self.try_hard_sigmoid().unwrap()
+    }
+    /// See [hard_sigmoid]
+    pub fn try_hard_sigmoid(self) -> Result<Self, D::Err> {
+        try_unary_op(HardSigmoidKernelOp, self)
+    }
+}
+
+/// [Hard Swish](https://pytorch.org/docs/stable/generated/torch.nn.Hardswish.html). `t * hard_sigmoid(t)`.
+///
+/// The derivative is `0` for `t < -3`, `t / 3 + 0.5` for `-3 <= t <= 3`, and `1` for `t > 3`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-3.0, 0.0, 3.0]);
+/// let r = t.hard_swish();
+/// assert_eq!(r.array(), [0.0, 0.0, 3.0]);
+/// ```
+pub fn hard_swish<S: Shape, E: Dtype, D: UnaryKernel<HardSwishKernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.hard_swish()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<HardSwishKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [hard_swish]
+    pub fn hard_swish(self) -> Self {
+        self.try_hard_swish().unwrap()
+    }
+    /// See [hard_swish]
+    pub fn try_hard_swish(self) -> Result<Self, D::Err> {
+        try_unary_op(HardSwishKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_hard_sigmoid() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-4.0, -3.0, -1.5, 1.5, 3.0, 4.0]);
+        let r = x.leaky_trace().hard_sigmoid();
+        assert_close(&r.array(), &[0.0, 0.0, 0.25, 0.75, 1.0, 1.0]);
+        let g = r.sum().backward();
+        let s = 1.0 / 6.0;
+        assert_close(&g.get(&x).array(), &[0.0, 0.0, s, s, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_hard_swish() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-4.0, -3.0, -1.5, 1.5, 3.0, 4.0]);
+        let r = x.leaky_trace().hard_swish();
+        assert_close(&r.array(), &[0.0, 0.0, -0.375, 1.125, 3.0, 4.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[0.0, -0.5, 0.0, 1.0, 1.5, 1.0]);
+    }
+}
@@ -0,0 +1,22 @@
+use super::LeakyReLUKernelOp as LeakyReLU;
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::DeviceRepr for LeakyReLU<f32> {}
+unsafe impl cudarc::driver::DeviceRepr for LeakyReLU<f64> {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/leaky_relu.ptx"));
+
+cuda_unary!(
+    LeakyReLU<f32>,
+    f32,
+    PTX,
+    "leaky_relu_fwd_f32",
+    "leaky_relu_bwd_f32"
+);
+cuda_unary!(
+    LeakyReLU<f64>,
+    f64,
+    PTX,
+    "leaky_relu_fwd_f64",
+    "leaky_relu_bwd_f64"
+);
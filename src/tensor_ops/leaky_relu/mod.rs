@@ -0,0 +1,78 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LeakyReLUKernelOp<E> {
+    pub slope: E,
+}
+
+/// [Leaky ReLU](https://pytorch.org/docs/stable/generated/torch.nn.LeakyReLU.html). `max(0, t) + slope * min(0, t)`
+///
+/// The derivative is `1` for `t >= 0` and `slope` otherwise. See [crate::tensor_ops::prelu()] for a version
+/// where the slope is a tensor.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.leaky_relu(0.05);
+/// assert_eq!(r.array(), [-0.05, 0.0, 1.0, 2.0]);
+/// ```
+pub fn leaky_relu<S: Shape, E: Dtype, D: UnaryKernel<LeakyReLUKernelOp<E>, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    slope: E,
+) -> Tensor<S, E, D, T> {
+    t.leaky_relu(slope)
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<LeakyReLUKernelOp<E>, E>, T: Tape<E, D>>
+    Tensor<S, E, D, T>
+{
+    /// See [leaky_relu]
+    pub fn leaky_relu(self, slope: E) -> Self {
+        self.try_leaky_relu(slope).unwrap()
+    }
+    /// See [leaky_relu]
+    pub fn try_leaky_relu(self, slope: E) -> Result<Self, D::Err> {
+        try_unary_op(LeakyReLUKernelOp { slope }, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_leaky_relu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.leaky_trace().leaky_relu(0.1);
+        assert_close(&r.array(), &[-0.2, -0.1, 0.0, 1.0, 2.0]);
+        // NOTE: call .exp() to make sure we cover cases where .leaky_relu() uses the result's gradient
+        let g = r.exp().mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.016374615, 0.018096748, 0.2, 0.54365635, 1.4778112],
+        );
+    }
+
+    #[test]
+    fn test_leaky_relu_matches_prelu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.ones() * 0.05;
+        let r1 = x.leaky_trace().leaky_relu(0.05);
+        let r2 = x.leaky_trace().prelu(a);
+        assert_close(&r1.array(), &r2.array());
+        let g1 = r1.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g1.get(&x).array(), &g2.get(&x).array());
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    shapes::*,
+    tensor::{cpu::NdIndex, *},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+use std::sync::Arc;
+
+impl<S: Shape, E: Dtype> Tensor<S, E, Cpu> {
+    /// Applies `f` to each element. This is not tracked on the tape, see
+    /// [Tensor::map_with_grad()] for a differentiable version.
+    ///
+    /// Useful for prototyping custom ops without writing a kernel.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([1.0, 2.0, 3.0]);
+    /// assert_eq!(t.map(|x| x * x).array(), [1.0, 4.0, 9.0]);
+    /// ```
+    pub fn map<F: Fn(E) -> E>(&self, f: F) -> Self {
+        let mut out = self.clone();
+        out.id = unique_id();
+        // out has the same strides as self, so the buffer can be mapped directly
+        for x in Arc::make_mut(&mut out.data).iter_mut() {
+            *x = f(*x);
+        }
+        out
+    }
+
+    /// Applies `f` to each pair of elements of `self` and `rhs`. This is not tracked
+    /// on the tape, see [Tensor::zip_with_grad()] for a differentiable version.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([1.0, 2.0, 3.0]);
+    /// let b = dev.tensor([4.0, 5.0, 6.0]);
+    /// assert_eq!(a.zip(&b, |x, y| x * y + 1.0).array(), [5.0, 11.0, 19.0]);
+    /// ```
+    pub fn zip<F: Fn(E, E) -> E>(&self, rhs: &Self, f: F) -> Self {
+        assert_eq!(self.shape, rhs.shape);
+        let mut out = self.device.zeros_like(&self.shape);
+        let mut lhs_idx = NdIndex::new(self.shape, self.strides);
+        let mut rhs_idx = NdIndex::new(rhs.shape, rhs.strides);
+        for o in Arc::make_mut(&mut out.data).iter_mut() {
+            let l = self.data[lhs_idx.next().unwrap()];
+            let r = rhs.data[rhs_idx.next().unwrap()];
+            *o = f(l, r);
+        }
+        out
+    }
+
+    /// Reduces axes `Ax` by folding each group of reduced elements with `f`, starting
+    /// from `init`. `f` should be associative. This is not tracked on the tape, see
+    /// [crate::tensor_ops::SumTo], [crate::tensor_ops::MaxTo], or [crate::tensor_ops::ProdTo]
+    /// for differentiable reductions.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let r = t.fold::<Rank1<2>, _, _>(0.0, |acc, x| acc + x * x);
+    /// assert_eq!(r.array(), [14.0, 77.0]);
+    /// ```
+    pub fn fold<Dst: Shape, Ax: Axes, F: Fn(E, E) -> E>(&self, init: E, f: F) -> Tensor<Dst, E, Cpu>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape.reduced();
+        let mut out = self.device.zeros_like(&dst);
+        let num_elems_reduced = <S as HasAxes<Ax>>::size(&self.shape);
+        let mut idx = index_for_reductions::<S, Ax>(self.shape, self.strides);
+        for o in Arc::make_mut(&mut out.data).iter_mut() {
+            let mut acc = init;
+            for _ in 0..num_elems_reduced {
+                acc = f(acc, self.data[idx.next().unwrap()]);
+            }
+            *o = acc;
+        }
+        out
+    }
+}
+
+impl<S: Shape, E: Dtype, T: Tape<E, Cpu>> Tensor<S, E, Cpu, T> {
+    /// Applies `f` to each element, using `df` as its derivative in the backward pass.
+    /// `df` receives the input element `x`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([1.0, 2.0, 3.0]);
+    /// let r = t.leaky_trace().map_with_grad(|x| x * x, |x| 2.0 * x);
+    /// assert_eq!(r.array(), [1.0, 4.0, 9.0]);
+    /// let g = r.sum().backward();
+    /// assert_eq!(g.get(&t).array(), [2.0, 4.0, 6.0]);
+    /// ```
+    pub fn map_with_grad<F, Df>(self, f: F, df: Df) -> Self
+    where
+        F: Fn(E) -> E,
+        Df: 'static + Fn(E) -> E,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.map(f);
+        let inp_ghost = inp.ghost();
+        let out_ghost = out.ghost();
+        tape.add_backward_op(move |grads| {
+            grads.try_alloc_for(&inp_ghost)?;
+            grads.try_alloc_for(&out_ghost)?;
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp_ghost, &out_ghost);
+            // inp & out have the same strides
+            for (i, g) in grad_inp.iter_mut().enumerate() {
+                *g += df(inp.data[i]) * grad_out[i];
+            }
+            Ok(())
+        });
+        out.put_tape(tape)
+    }
+
+    /// Applies `f` to each pair of elements of `self` and `rhs`, using `dfdx` and `dfdy` as
+    /// its partial derivatives in the backward pass. Both receive the input elements `x` and `y`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([1.0, 2.0]);
+    /// let b = dev.tensor([3.0, 4.0]);
+    /// let r = a.leaky_trace().zip_with_grad(b.clone(), |x, y| x * y, |_, y| y, |x, _| x);
+    /// let g = r.sum().backward();
+    /// assert_eq!(g.get(&a).array(), [3.0, 4.0]);
+    /// ```
+    pub fn zip_with_grad<R, F, Dfdx, Dfdy>(
+        self,
+        rhs: Tensor<S, E, Cpu, R>,
+        f: F,
+        dfdx: Dfdx,
+        dfdy: Dfdy,
+    ) -> Self
+    where
+        T: Merge<R>,
+        F: Fn(E, E) -> E,
+        Dfdx: 'static + Fn(E, E) -> E,
+        Dfdy: 'static + Fn(E, E) -> E,
+    {
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = rhs.split_tape();
+        let mut tape = ltape.merge(rtape);
+        let out = lhs.zip(&rhs, f);
+        let lhs_ghost = lhs.ghost();
+        let rhs_ghost = rhs.ghost();
+        let out_ghost = out.ghost();
+        tape.add_backward_op(move |grads| {
+            grads.try_alloc_for(&lhs_ghost)?;
+            grads.try_alloc_for(&rhs_ghost)?;
+            grads.try_alloc_for(&out_ghost)?;
+            let (grad_lhs, grad_rhs, grad_out) =
+                grads.muts_and_ref(&lhs_ghost, &rhs_ghost, &out_ghost);
+            let mut lhs_idx = NdIndex::new(lhs.shape, lhs.strides);
+            let mut rhs_idx = NdIndex::new(rhs.shape, rhs.strides);
+            // out is contiguous
+            for &go in grad_out.iter() {
+                let l = lhs_idx.next().unwrap();
+                let r = rhs_idx.next().unwrap();
+                let (x, y) = (lhs.data[l], rhs.data[r]);
+                grad_lhs[l] += dfdx(x, y) * go;
+                grad_rhs[r] += dfdy(x, y) * go;
+            }
+            Ok(())
+        });
+        out.put_tape(tape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_map_square() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[-2.0, -1.0, 0.0], [1.0, 2.0, 3.0]]);
+
+        assert_eq!(t.map(|x| x * x).array(), t.clone().square().array());
+
+        let r = t.leaky_trace().map_with_grad(|x| x * x, |x| 2.0 * x);
+        let g = r.sum().backward();
+        assert_close(&g.get(&t).array(), &(t.clone() * 2.0).array());
+    }
+
+    #[test]
+    fn test_fold_matches_max_and_prod() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+
+        let r = t.fold::<Rank2<2, 4>, _, _>(TestDtype::NEG_INFINITY, TestDtype::max);
+        assert_eq!(r.array(), t.clone().max::<Rank2<2, 4>, _>().array());
+
+        let r = t.fold::<Rank1<3>, _, _>(1.0, |acc, x| acc * x);
+        assert_close(&r.array(), &t.clone().prod::<Rank1<3>, _>().array());
+
+        let r = t.fold::<Rank0, _, _>(0.0, |acc, x| acc + x);
+        assert_close(&r.array(), &t.sum::<Rank0, _>().array());
+    }
+
+    #[test]
+    fn test_zip_matches_mul() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+
+        let r1 = a.leaky_trace().zip_with_grad(
+            b.leaky_trace().broadcast(),
+            |x, y| x * y,
+            |_, y| y,
+            |x, _| x,
+        );
+        let r2 = a.leaky_trace() * b.leaky_trace().broadcast();
+        assert_close(&r1.array(), &r2.array());
+
+        let g1 = r1.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g1.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g1.get(&b).array(), &g2.get(&b).array());
+    }
+}
@@ -3,7 +3,7 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use crate::{shapes::*, tensor::*};
+use crate::{shapes::*, tensor::*, tensor_ops::BroadcastTo};
 
 pub trait MaxReduceKernel<E: Dtype>: DeviceStorage {
     fn forward<Src: Shape, Dst: Shape, Ax: Axes>(
@@ -81,6 +81,36 @@ impl<S: Shape, E: Dtype, D: MaxReduceKernel<E>, T: Tape<E, D>> MaxTo for Tensor<
     }
 }
 
+impl<S: Shape, E: Dtype, D: MaxReduceKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// Max reduction that keeps the reduced axes with length 1.
+    /// **Pytorch equivalent**: `t.amax(Ax, keepdim=True)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r: Tensor<Rank2<2, 1>, f32, _> = t.max_keepdim::<Axis<1>>();
+    /// assert_eq!(r.array(), [[3.0], [-1.0]]);
+    /// ```
+    pub fn max_keepdim<Ax: Axes>(self) -> Tensor<KeepDim<S, Ax>, E, D, T>
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_max_keepdim::<Ax>().unwrap()
+    }
+
+    /// Fallible version of [Tensor::max_keepdim]
+    #[allow(clippy::type_complexity)]
+    pub fn try_max_keepdim<Ax: Axes>(self) -> Result<Tensor<KeepDim<S, Ax>, E, D, T>, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        let dst = <S as ReduceKeepDimTo<Ax>>::keep_dim(self.shape());
+        self.try_max::<<S as ReduceShape<Ax>>::Reduced, Ax>()?
+            .try_broadcast_like::<KeepDim<S, Ax>, Ax>(&dst)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,4 +165,14 @@ mod tests {
             [[1.0, 1.0], [1.0, 1.0], [0.0, 1.0], [0.0, 1.0]]
         );
     }
+
+    #[test]
+    fn test_max_keepdim() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, -1.0], [3.0, -2.0, 2.0]]);
+        let r: Tensor<Rank2<1, 3>, TestDtype, _, _> = t.leaky_trace().max_keepdim::<Axis<0>>();
+        assert_eq!(r.array(), [[3.0, 2.0, 2.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 1.0, 0.0], [1.0, 0.0, 1.0]]);
+    }
 }
@@ -44,6 +44,36 @@ impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> MeanTo for Tensor<S, E, D,
     }
 }
 
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// Mean reduction that keeps the reduced axes with length 1.
+    /// **Pytorch equivalent**: `t.mean(Ax, keepdim=True)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let r: Tensor<Rank2<2, 1>, f32, _> = t.mean_keepdim::<Axis<1>>();
+    /// assert_eq!(r.array(), [[2.0], [5.0]]);
+    /// ```
+    pub fn mean_keepdim<Ax: Axes>(self) -> Tensor<KeepDim<S, Ax>, E, D, T>
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_mean_keepdim::<Ax>().unwrap()
+    }
+
+    /// Fallible version of [Tensor::mean_keepdim]
+    #[allow(clippy::type_complexity)]
+    pub fn try_mean_keepdim<Ax: Axes>(self) -> Result<Tensor<KeepDim<S, Ax>, E, D, T>, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        let dst = <S as ReduceKeepDimTo<Ax>>::keep_dim(self.shape());
+        self.try_mean::<<S as ReduceShape<Ax>>::Reduced, Ax>()?
+            .try_broadcast_like::<KeepDim<S, Ax>, Ax>(&dst)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +173,14 @@ mod tests {
         let r2 = t.sum::<_, Axis<0>>().sum::<_, Axis<0>>() / 6.0;
         assert_close(&r.array(), &r2.array());
     }
+
+    #[test]
+    fn test_mean_keepdim() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let r: Tensor<Rank2<2, 1>, TestDtype, _, _> = t.leaky_trace().mean_keepdim::<Axis<1>>();
+        assert_eq!(r.array(), [[2.0], [5.0]]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&t).array(), &[[1.0 / 3.0; 3]; 2]);
+    }
 }
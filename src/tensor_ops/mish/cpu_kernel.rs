@@ -0,0 +1,18 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::MishKernelOp {
+    const DF_USES_FX: bool = false;
+    const HAS_CONST_DF: bool = false;
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let softplus = x.max(F::zero()) + x.abs().neg().exp().ln_1p();
+        x * softplus.tanh()
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let softplus = x.max(F::zero()) + x.abs().neg().exp().ln_1p();
+        let tanh = softplus.tanh();
+        let sigmoid = F::one() / (F::one() + x.neg().exp());
+        tanh + x * (F::one() - tanh * tanh) * sigmoid
+    }
+}
@@ -0,0 +1,9 @@
+use super::MishKernelOp;
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::DeviceRepr for MishKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/mish.ptx"));
+
+cuda_unary!(MishKernelOp, f32, PTX, "mish_fwd_f32", "mish_bwd_f32");
+cuda_unary!(MishKernelOp, f64, PTX, "mish_fwd_f64", "mish_bwd_f64");
@@ -0,0 +1,78 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct MishKernelOp;
+
+/// [Mish](https://arxiv.org/abs/1908.08681). `t * tanh(softplus(t))`.
+///
+/// Uses the same overflow-free [softplus()] as the softplus op.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.mish();
+/// ```
+pub fn mish<S: Shape, E: Dtype, D: UnaryKernel<MishKernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.mish()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<MishKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [mish]
+    pub fn mish(self) -> Self {
+        self.try_mish().unwrap()
+    }
+    /// See [mish]
+    pub fn try_mish(self) -> Result<Self, D::Err> {
+        try_unary_op(MishKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_mish() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.leaky_trace().mish();
+        assert_close(
+            &r.array(),
+            &[-0.25250148, -0.30340146, 0.0, 0.8650984, 1.943959],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[-0.021671018, 0.011843351, 0.12, 0.20980724, 0.21386358],
+        );
+    }
+
+    #[test]
+    fn test_mish_large_inputs() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1000.0, 1000.0]);
+        let r = x.leaky_trace().mish();
+        assert_eq!(r.array(), [0.0, 1000.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_mish_finite_differences() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal::<Rank1<8>>() * 3.0;
+        let err = check_gradient(|x| x.mish().sum(), &x, 1e-3);
+        assert!(err < 1e-2, "{err}");
+    }
+}
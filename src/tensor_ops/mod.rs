@@ -151,8 +151,10 @@ pub use utilities::*;
 
 mod abs;
 mod add;
+mod attention;
 mod attention_reshape;
 pub(crate) mod axpy;
+mod batches;
 mod bce;
 mod boolean;
 mod broadcast_to;
@@ -160,28 +162,41 @@ mod choose;
 mod clamp;
 mod cmp;
 mod concat;
+mod conv1d;
 mod cos;
+mod diagonal;
 mod div;
+mod dot;
 mod dropout;
+mod elu;
 mod exp;
+mod flatten;
+mod gather_dim;
 mod gelu;
+mod hard_sigmoid;
 mod huber_error;
+mod leaky_relu;
 mod ln;
 mod log_softmax;
 mod logsumexp_to;
+mod map;
 mod matmul;
 mod max_to;
 mod maximum;
 mod mean_to;
 mod min_to;
 mod minimum;
+mod mish;
 mod mul;
 mod nans_to;
 mod negate;
+mod norm;
 mod normalize;
+mod pad;
 mod permute_to;
 mod pow;
 mod prelu;
+mod prod_to;
 mod realize_to;
 mod recip;
 mod relu;
@@ -192,6 +207,7 @@ mod sigmoid;
 mod sin;
 mod slice;
 mod softmax;
+mod softplus;
 mod sqrt;
 mod square;
 mod stack;
@@ -200,26 +216,36 @@ mod sub;
 mod sum_to;
 mod tanh;
 mod to_dtype;
+mod topk;
 mod tri;
 mod var_to;
 
 pub use abs::abs;
 pub use add::{add, TryAdd};
+pub use attention::scaled_dot_product_attention;
 pub use attention_reshape::TryAttentionReshape;
 pub use axpy::axpy;
 pub use bce::bce_with_logits;
-pub use boolean::{bool_and, bool_not, bool_or, bool_xor};
+pub use boolean::{all, any, bool_and, bool_not, bool_or, bool_xor, count_nonzero};
 pub use broadcast_to::BroadcastTo;
 pub use choose::ChooseFrom;
 pub use clamp::clamp;
 pub use cmp::{eq, ge, gt, le, lt, ne};
 pub use concat::TryConcat;
+pub use conv1d::conv1d;
 pub use cos::cos;
+pub use diagonal::{diagonal, matrix_trace};
 pub use div::{div, TryDiv};
-pub use dropout::dropout;
-pub use exp::exp;
+pub use dot::{dot, outer};
+pub use dropout::{dropout, dropout_with_mask};
+pub use elu::{elu, selu};
+pub use exp::{exp, exp_clamped};
+pub use flatten::flatten_from_axis;
+pub use gather_dim::gather_dim;
 pub use gelu::gelu;
+pub use hard_sigmoid::{hard_sigmoid, hard_swish};
 pub use huber_error::huber_error;
+pub use leaky_relu::leaky_relu;
 pub use ln::ln;
 pub use log_softmax::log_softmax;
 pub use logsumexp_to::LogSumExpTo;
@@ -229,13 +255,17 @@ pub use maximum::maximum;
 pub use mean_to::MeanTo;
 pub use min_to::MinTo;
 pub use minimum::minimum;
+pub use mish::mish;
 pub use mul::{mul, TryMul};
 pub use nans_to::nans_to;
 pub use negate::negate;
+pub use norm::{norm, NormOrd};
 pub use normalize::normalize;
+pub use pad::{pad, PadMode};
 pub use permute_to::PermuteTo;
 pub use pow::{powf, powi};
 pub use prelu::{leakyrelu, prelu, TryPReLU};
+pub use prod_to::ProdTo;
 pub use realize_to::RealizeTo;
 pub use recip::recip;
 pub use relu::relu;
@@ -246,14 +276,16 @@ pub use sigmoid::sigmoid;
 pub use sin::sin;
 pub use slice::slice;
 pub use softmax::softmax;
+pub use softplus::softplus;
 pub use sqrt::sqrt;
 pub use square::square;
 pub use stack::TryStack;
 pub use stddev_to::StddevTo;
 pub use sub::{sub, TrySub};
-pub use sum_to::SumTo;
+pub use sum_to::{reduce_to, SumTo};
 pub use tanh::tanh;
 pub use to_dtype::to_dtype;
+pub use topk::topk;
 pub use tri::{lower_tri, upper_tri};
 pub use var_to::VarTo;
 
@@ -262,7 +294,7 @@ pub(crate) use to_dtype::ToDtypeKernel;
 #[cfg(feature = "nightly")]
 mod conv2d;
 #[cfg(feature = "nightly")]
-pub use conv2d::{TryConv2D, TryConv2DTo};
+pub use conv2d::{TryConv2D, TryConv2DGroupedTo, TryConv2DTo};
 
 #[cfg(feature = "nightly")]
 mod convtrans2d;
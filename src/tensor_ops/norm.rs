@@ -0,0 +1,139 @@
+use crate::{
+    shapes::{Axes, BroadcastShapeTo, Dtype, ReduceShapeTo, Shape},
+    tensor::{HasErr, NoneTape, Tape, Tensor},
+};
+
+use super::{BroadcastTo, ChooseFrom, Device, MaxTo, SumTo, TryDiv, TryMul};
+
+/// Which norm [Tensor::norm()] computes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormOrd {
+    /// `sum(|t|)`
+    L1,
+    /// `sqrt(sum(t^2))`, also known as the frobenius norm for matrices.
+    L2,
+    /// `max(|t|)`
+    Inf,
+}
+
+/// The Lp norm of `t` along the axes `Ax`.
+///
+/// The gradient of [NormOrd::L2] is `t / norm`, or zero where the norm is zero.
+///
+/// **Pytorch equivalent**: `torch.linalg.vector_norm(t, ord, dim=Ax)`
+///
+/// Over all elements:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[3.0, -4.0], [0.0, 0.0]]);
+/// assert_eq!(t.clone().norm::<Rank0, _>(NormOrd::L1).array(), 7.0);
+/// assert_eq!(t.clone().norm::<Rank0, _>(NormOrd::L2).array(), 5.0);
+/// assert_eq!(t.norm::<Rank0, _>(NormOrd::Inf).array(), 4.0);
+/// ```
+///
+/// Along an axis:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[3.0, -4.0], [0.0, 0.0]]);
+/// assert_eq!(t.norm::<_, Axis<1>>(NormOrd::L2).array(), [5.0, 0.0]);
+/// ```
+pub fn norm<Dst: Shape, Ax: Axes, S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    ord: NormOrd,
+) -> Tensor<Dst, E, D, T>
+where
+    S: ReduceShapeTo<Dst, Ax>,
+    Dst: BroadcastShapeTo<S, Ax>,
+{
+    t.norm::<Dst, Ax>(ord)
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [norm()]
+    pub fn norm<Dst: Shape, Ax: Axes>(self, ord: NormOrd) -> Tensor<Dst, E, D, T>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+        Dst: BroadcastShapeTo<S, Ax>,
+    {
+        self.try_norm::<Dst, Ax>(ord).unwrap()
+    }
+
+    /// See [norm()]
+    pub fn try_norm<Dst: Shape, Ax: Axes>(
+        self,
+        ord: NormOrd,
+    ) -> Result<Tensor<Dst, E, D, T>, <Self as HasErr>::Err>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+        Dst: BroadcastShapeTo<S, Ax>,
+    {
+        match ord {
+            NormOrd::L1 => self.try_abs()?.try_sum(),
+            NormOrd::Inf => self.try_abs()?.try_max(),
+            NormOrd::L2 => {
+                // sum(t * (t / norm)) has the value of the norm, and a gradient of
+                // t / norm, since the right hand side is not on the tape.
+                let shape = self.shape;
+                let t = self.retaped::<NoneTape>();
+                let norm = t.clone().try_square()?.try_sum::<Dst, Ax>()?.try_sqrt()?;
+                let ones = t.device.try_ones_like(&norm.shape)?;
+                let norm = norm.try_scalar_eq(E::default())?.try_choose(ones, norm)?;
+                let scaled = t.try_div(norm.try_broadcast_like(&shape)?)?;
+                self.try_mul(scaled)?.try_sum()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_norm_all_elements() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[1.0, -2.0, 3.0], [-4.0, 0.5, 0.0]]);
+
+        let r = t.leaky_trace().norm::<Rank0, _>(NormOrd::L1);
+        assert_close(&r.array(), &10.5);
+        let g = r.backward();
+        assert_eq!(g.get(&t).array(), [[1.0, -1.0, 1.0], [-1.0, 1.0, 0.0]]);
+
+        let r = t.leaky_trace().norm::<Rank0, _>(NormOrd::Inf);
+        assert_close(&r.array(), &4.0);
+        let g = r.backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 0.0, 0.0], [-1.0, 0.0, 0.0]]);
+
+        let r = t.leaky_trace().norm::<Rank0, _>(NormOrd::L2);
+        let n = (1.0 + 4.0 + 9.0 + 16.0 + 0.25 as TestDtype).sqrt();
+        assert_close(&r.array(), &n);
+        let g = r.backward();
+        assert_close(&g.get(&t).array(), &(t.clone() / n).array());
+    }
+
+    #[test]
+    fn test_norm_l2_axis_finite_differences() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([0.5, -1.5]);
+        let err = check_gradient(
+            |t| (t.norm::<Rank1<2>, _>(NormOrd::L2) * w.clone()).sum(),
+            &t,
+            1e-2,
+        );
+        assert!(err < 1e-2, "{err}");
+    }
+
+    #[test]
+    fn test_norm_l2_zero_has_zero_grad() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[0.0, 0.0], [3.0, 4.0]]);
+        let r = t.leaky_trace().norm::<_, Axis<1>>(NormOrd::L2);
+        assert_eq!(r.array(), [0.0, 5.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&t).array(), &[[0.0, 0.0], [0.6, 0.8]]);
+    }
+}
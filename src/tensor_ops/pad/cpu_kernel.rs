@@ -0,0 +1,77 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{cpu::NdIndex, Cpu, Tensor},
+};
+
+use std::sync::Arc;
+
+use super::{PadMode, PadOp};
+
+impl<E> PadOp<E> {
+    /// Maps an index along the padded axis of the output to the index along the
+    /// same axis of the input, or `None` if it is in a constant padded region.
+    fn src_index(&self, dst: usize, size: usize) -> Option<usize> {
+        let i = dst as isize - self.before as isize;
+        let n = size as isize;
+        match self.mode {
+            _ if (0..n).contains(&i) => Some(i as usize),
+            PadMode::Constant(_) => None,
+            PadMode::Reflect if i < 0 => Some((-i) as usize),
+            PadMode::Reflect => Some((2 * (n - 1) - i) as usize),
+        }
+    }
+}
+
+impl<E: Dtype> super::PadKernel<E> for Cpu {
+    fn forward<S: Shape, Dst: Shape<Concrete = S::Concrete>>(
+        &self,
+        op: PadOp<E>,
+        inp: &Tensor<S, E, Self>,
+        out: &mut Tensor<Dst, E, Self>,
+    ) -> Result<(), Self::Err> {
+        if out.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let size = inp.shape.concrete()[op.axis];
+        let buf = inp.data.as_ref();
+        let mut out_idx = NdIndex::new(out.shape, out.strides);
+        let out_buf = Arc::make_mut(&mut out.data);
+        while let Some((o, mut idx)) = out_idx.next_with_idx() {
+            out_buf[o] = match op.src_index(idx[op.axis], size) {
+                Some(i) => {
+                    idx[op.axis] = i;
+                    let i: usize = idx.into_iter().zip(inp.strides).map(|(i, s)| i * s).sum();
+                    buf[i]
+                }
+                None => match op.mode {
+                    PadMode::Constant(value) => value,
+                    PadMode::Reflect => unreachable!(),
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn backward<S: Shape, Dst: Shape<Concrete = S::Concrete>>(
+        &self,
+        op: PadOp<E>,
+        inp: &Tensor<S, E, Self>,
+        out_shape: Dst,
+        grad_inp: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        if out_shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let size = inp.shape.concrete()[op.axis];
+        let mut out_idx = NdIndex::new(out_shape, out_shape.strides());
+        while let Some((o, mut idx)) = out_idx.next_with_idx() {
+            if let Some(i) = op.src_index(idx[op.axis], size) {
+                idx[op.axis] = i;
+                let i: usize = idx.into_iter().zip(inp.strides).map(|(i, s)| i * s).sum();
+                grad_inp[i] += grad_out[o];
+            }
+        }
+        Ok(())
+    }
+}
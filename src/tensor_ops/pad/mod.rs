@@ -0,0 +1,218 @@
+use crate::{shapes::*, tensor::*};
+
+mod cpu_kernel;
+
+/// How to fill the padded region in [pad()].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<E> {
+    /// Fills the padded region with a constant value.
+    ///
+    /// **Pytorch equivalent**: `F.pad(..., mode="constant", value=...)`
+    Constant(E),
+    /// Mirrors the values at the edges, without repeating the edge values.
+    /// `before` and `after` must be smaller than the size of the padded axis.
+    ///
+    /// **Pytorch equivalent**: `F.pad(..., mode="reflect")`
+    Reflect,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PadOp<E> {
+    pub axis: usize,
+    pub before: usize,
+    pub after: usize,
+    pub mode: PadMode<E>,
+}
+
+pub trait PadKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape, Dst: Shape<Concrete = S::Concrete>>(
+        &self,
+        op: PadOp<E>,
+        inp: &Tensor<S, E, Self>,
+        out: &mut Tensor<Dst, E, Self>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<S: Shape, Dst: Shape<Concrete = S::Concrete>>(
+        &self,
+        op: PadOp<E>,
+        inp: &Tensor<S, E, Self>,
+        out_shape: Dst,
+        grad_inp: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Pads axis `Ax` with `before` elements at the start and `after` elements at the end.
+/// The padded dimension becomes a `usize`.
+///
+/// Constant padding:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0, 2.0, 3.0]);
+/// let r = t.pad::<Axis<0>>(1, 2, PadMode::Constant(0.0));
+/// assert_eq!(r.as_vec(), [0.0, 1.0, 2.0, 3.0, 0.0, 0.0]);
+/// ```
+///
+/// Reflect padding:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0, 2.0, 3.0]);
+/// let r = t.pad::<Axis<0>>(2, 1, PadMode::Reflect);
+/// assert_eq!(r.as_vec(), [3.0, 2.0, 1.0, 2.0, 3.0, 2.0]);
+/// ```
+pub fn pad<Ax: Axes<Array = [isize; 1]>, S, E: Dtype, D: PadKernel<E> + ZerosTensor<E>, T>(
+    t: Tensor<S, E, D, T>,
+    before: usize,
+    after: usize,
+    mode: PadMode<E>,
+) -> Tensor<S::Resized, E, D, T>
+where
+    S: ResizeDimShape<Ax>,
+    T: Tape<E, D>,
+{
+    t.pad::<Ax>(before, after, mode)
+}
+
+impl<S: Shape, E: Dtype, D: PadKernel<E> + ZerosTensor<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [pad()]
+    pub fn pad<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        before: usize,
+        after: usize,
+        mode: PadMode<E>,
+    ) -> Tensor<S::Resized, E, D, T>
+    where
+        S: ResizeDimShape<Ax>,
+    {
+        self.try_pad::<Ax>(before, after, mode).unwrap()
+    }
+
+    /// See [pad()]
+    pub fn try_pad<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        before: usize,
+        after: usize,
+        mode: PadMode<E>,
+    ) -> Result<Tensor<S::Resized, E, D, T>, D::Err>
+    where
+        S: ResizeDimShape<Ax>,
+    {
+        let axis = Ax::as_array()[0] as usize;
+        let size = self.shape.concrete()[axis];
+        if mode == PadMode::Reflect {
+            assert!(
+                before < size && after < size,
+                "Reflect padding ({before}, {after}) must be smaller than the padded dimension {size}"
+            );
+        }
+        let op = PadOp {
+            axis,
+            before,
+            after,
+            mode,
+        };
+        let (inp, mut tape) = self.split_tape();
+        let out_shape = inp.shape.resize_dim(size + before + after);
+        let mut out = inp.device.try_zeros_like(&out_shape)?;
+        inp.device.forward(op, &inp, &mut out)?;
+        let inp_ghost = inp.ghost();
+        let out_ghost = out.ghost();
+        tape.add_backward_op(move |grads| {
+            grads.try_alloc_for(&inp_ghost)?;
+            grads.try_alloc_for(&out_ghost)?;
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp_ghost, &out_ghost);
+            inp.device.backward(op, &inp, out_shape, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_pad_constant_2d() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let r = t
+            .leaky_trace()
+            .pad::<Axis<0>>(1, 1, PadMode::Constant(-1.0));
+        assert_eq!(r.shape(), &(4, Const::<3>));
+        assert_eq!(
+            r.as_vec(),
+            [-1.0, -1.0, -1.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, -1.0, -1.0, -1.0]
+        );
+
+        let r = r.pad::<Axis<1>>(0, 2, PadMode::Constant(9.0));
+        assert_eq!(r.shape(), &(4, 5));
+        assert_eq!(
+            r.as_vec(),
+            [
+                -1.0, -1.0, -1.0, 9.0, 9.0, 1.0, 2.0, 3.0, 9.0, 9.0, 4.0, 5.0, 6.0, 9.0, 9.0, -1.0,
+                -1.0, -1.0, 9.0, 9.0
+            ]
+        );
+
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &t.exp().array());
+    }
+
+    #[test]
+    fn test_pad_reflect_1d() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<4>, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+
+        let r = t.leaky_trace().pad::<Axis<0>>(2, 3, PadMode::Reflect);
+        assert_eq!(r.shape(), &(9,));
+        assert_eq!(r.as_vec(), [3.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 1.0]);
+
+        // each element receives the gradient of every copy of it
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [2.0, 3.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_pad_strided_input() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([1.0, 2.0]);
+
+        let r = t
+            .leaky_trace()
+            .broadcast::<Rank2<3, 2>, _>()
+            .pad::<Axis<1>>(1, 0, PadMode::Reflect);
+        assert_eq!(r.as_vec(), [2.0, 1.0, 2.0, 2.0, 1.0, 2.0, 2.0, 1.0, 2.0]);
+
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [3.0, 6.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pad_reflect_too_large() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<3>, TestDtype, _> = dev.zeros();
+        let _ = t.pad::<Axis<0>>(3, 0, PadMode::Reflect);
+    }
+
+    #[test]
+    fn test_pad_zero_size() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<(usize, Const<3>), TestDtype, _> = dev.zeros_like(&(0, Const));
+        let r = t.clone().pad::<Axis<1>>(1, 1, PadMode::Constant(1.0));
+        assert_eq!(r.shape(), &(0, 5));
+
+        let op = PadOp {
+            axis: 1,
+            before: 1,
+            after: 1,
+            mode: PadMode::Constant(1.0),
+        };
+        let mut grad_inp = std::vec::Vec::new();
+        PadKernel::backward(&dev, op, &t, (0, 5), &mut grad_inp, &std::vec::Vec::new()).unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{shapes::*, tensor::*};
 
-use super::{BroadcastTo, ChooseFrom, Device, TryMul};
+use super::{ChooseFrom, Device, TryMul};
 
 /// [Parametric Rectified Linear Unit (PReLU)](https://pytorch.org/docs/stable/generated/torch.nn.PReLU.html). `max(0, lhs) + rhs*min(0, lhs)`
 ///
@@ -25,12 +25,12 @@ pub fn prelu<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D> + Merge<R>, R: Defa
     lhs.prelu(rhs)
 }
 
-/// Computes `prelu`, but with a scalar value. `max(0, t) + a*min(0, t)`
+/// Computes `prelu`, but with a scalar value. `max(0, t) + a*min(0, t)`. Same as [crate::tensor_ops::leaky_relu()].
 pub fn leakyrelu<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>>(
     lhs: Tensor<S, E, D, T>,
     rhs: E,
 ) -> Tensor<S, E, D, T> {
-    lhs.prelu(rhs)
+    lhs.leaky_relu(rhs)
 }
 
 /// [Parametric Rectified Linear Unit (PReLU)](https://pytorch.org/docs/stable/generated/torch.nn.PReLU.html). `max(0, lhs) + rhs*min(0, lhs)`
@@ -73,10 +73,7 @@ where
 impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<E, D>> TryPReLU<E> for Tensor<S, E, D, T> {
     /// See [prelu]
     fn try_prelu(self, rhs: E) -> Result<Self, Self::Err> {
-        let dev = self.device.clone();
-        let scale = dev.tensor(rhs).retaped::<T>().broadcast_like(self.shape());
-        let scaled = self.with_empty_tape().try_mul(scale)?;
-        self.try_scalar_lt(E::default())?.try_choose(scaled, self)
+        self.try_leaky_relu(rhs)
     }
 }
 
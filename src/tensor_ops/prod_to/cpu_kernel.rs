@@ -0,0 +1,67 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
+    tensor::{Cpu, Tensor, ZerosTensor},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+use std::vec::Vec;
+
+impl<E: Dtype> super::ProdKernel<E> for Cpu {
+    fn forward<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Tensor<Src, E, Self>,
+    ) -> Result<Tensor<Dst, E, Self>, Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>,
+    {
+        let mut out = self.try_zeros_like(&dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let inp_buf = inp.data.as_ref();
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        for o in out.buf_iter_mut() {
+            let mut tmp = E::ONE;
+            for _ in 0..num_elems_reduced {
+                tmp *= inp_buf[idx.next().unwrap()];
+            }
+            *o = tmp;
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Tensor<Src, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>,
+    {
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let inp_buf = inp.data.as_ref();
+        let mut inp_idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        let mut group: Vec<usize> = Vec::with_capacity(num_elems_reduced);
+        let mut prefix: Vec<E> = Vec::with_capacity(num_elems_reduced);
+
+        for &go in grad_out.iter() {
+            group.clear();
+            prefix.clear();
+            // prefix[j] is the product of the elements before j
+            let mut tmp = E::ONE;
+            for _ in 0..num_elems_reduced {
+                let i = inp_idx.next().unwrap();
+                group.push(i);
+                prefix.push(tmp);
+                tmp *= inp_buf[i];
+            }
+            // multiply by the product of the elements after j, so no division is needed
+            let mut suffix = E::ONE;
+            for (&i, &p) in group.iter().zip(prefix.iter()).rev() {
+                grad_inp[i] += go * p * suffix;
+                suffix *= inp_buf[i];
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+mod cpu_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait ProdKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Tensor<Src, E, Self>,
+    ) -> Result<Tensor<Dst, E, Self>, Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>;
+    fn backward<Src: Shape, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Tensor<Src, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reduction along multiple axes using multiplication.
+pub trait ProdTo: HasErr + HasShape {
+    /// Product reduction. **Pytorch equivalent**: `t.prod(Ax)`
+    ///
+    /// The gradient of each element is the product of all the other elements that were
+    /// reduced with it, which is also correct when some of them are zero.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r = t.prod::<Rank1<2>, _>(); // or `prod::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [6.0, -6.0]);
+    /// ```
+    fn prod<Dst: Shape, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_prod().unwrap()
+    }
+    /// Fallible version of [ProdTo::prod]
+    fn try_prod<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: ProdKernel<E>, T: Tape<E, D>> ProdTo for Tensor<S, E, D, T> {
+    fn try_prod<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.forward(dst, &inp)?;
+        let inp_ghost = inp.ghost();
+        let out_ghost = out.ghost();
+        tape.add_backward_op(move |grads| {
+            grads.try_alloc_for(&inp_ghost)?;
+            grads.try_alloc_for(&out_ghost)?;
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp_ghost, &out_ghost);
+            inp.device.backward::<S, Dst, Ax>(&inp, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_prod_axis_1_2d() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, 0.5, 4.0]]);
+        let r = t.leaky_trace().prod::<_, Axis<1>>();
+        assert_eq!(r.array(), [6.0, -2.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&t).array(), &[[6.0, 3.0, 2.0], [2.0, -4.0, -0.5]]);
+    }
+
+    #[test]
+    fn test_prod_with_zero() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[2.0, 0.0, 3.0], [0.0, 0.0, 5.0]]);
+        let r = t.leaky_trace().prod::<_, Axis<1>>();
+        assert_eq!(r.array(), [0.0, 0.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 6.0, 0.0], [0.0, 0.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_prod_axes_3d_to_1d() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.leaky_trace().prod::<Rank1<4>, _>();
+        let r2 = t.leaky_trace().prod::<_, Axis<0>>().prod::<_, Axis<0>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.mean().backward();
+        let g2 = r2.mean().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_prod_gradient_is_prod_over_x() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<5>, TestDtype, _> = dev.tensor([0.5, 1.5, -2.0, 3.0, 0.25]);
+        let r = t.leaky_trace().prod::<Rank0, _>();
+        let p = r.array();
+        let g = r.backward();
+        assert_close(&g.get(&t).array(), &t.array().map(|x| p / x));
+    }
+}
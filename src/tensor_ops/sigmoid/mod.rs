@@ -40,7 +40,7 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<SigmoidKernelOp, E>, T: Tape<E, D>> Tens
 
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tensor_ops::*, tests::*};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     #[test]
     fn test_sigmoid() {
@@ -57,4 +57,24 @@ mod tests {
             &[0.020998716, 0.039322387, 0.05, 0.039322387, 0.020998726],
         );
     }
+
+    #[test]
+    fn test_sigmoid_large_inputs() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1000.0, -100.0, 100.0, 1000.0]);
+        let r = x.leaky_trace().sigmoid();
+        let r_arr = r.array();
+        assert!(r_arr.iter().all(|v| v.is_finite()));
+        assert_close(&r_arr, &[0.0, 0.0, 1.0, 1.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[0.0; 4]);
+    }
+
+    #[test]
+    fn test_sigmoid_finite_differences() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal::<Rank1<8>>() * 3.0;
+        let err = check_gradient(|x| x.sigmoid().sum(), &x, 1e-3);
+        assert!(err < 1e-2, "{err}");
+    }
 }
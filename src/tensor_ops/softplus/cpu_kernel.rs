@@ -0,0 +1,14 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::SoftplusKernelOp {
+    const DF_USES_FX: bool = false;
+    const HAS_CONST_DF: bool = false;
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        x.max(F::zero()) + x.abs().neg().exp().ln_1p()
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        F::one() / (F::one() + x.neg().exp())
+    }
+}
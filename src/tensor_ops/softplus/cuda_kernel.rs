@@ -0,0 +1,21 @@
+use super::SoftplusKernelOp;
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::DeviceRepr for SoftplusKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/softplus.ptx"));
+
+cuda_unary!(
+    SoftplusKernelOp,
+    f32,
+    PTX,
+    "softplus_fwd_f32",
+    "softplus_bwd_f32"
+);
+cuda_unary!(
+    SoftplusKernelOp,
+    f64,
+    PTX,
+    "softplus_fwd_f64",
+    "softplus_bwd_f64"
+);
@@ -0,0 +1,87 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SoftplusKernelOp;
+
+/// [Softplus](https://en.wikipedia.org/wiki/Softplus). `ln(1 + exp(t))`.
+///
+/// Computed as `max(t, 0) + ln(1 + exp(-|t|))`, so it doesn't overflow for large `t`.
+///
+/// The derivative is `sigmoid(t)`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 1000.0]);
+/// let r = t.softplus();
+/// assert_eq!(r.array()[3], 1000.0);
+/// ```
+pub fn softplus<S: Shape, E: Dtype, D: UnaryKernel<SoftplusKernelOp, E>, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.softplus()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<SoftplusKernelOp, E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [softplus]
+    pub fn softplus(self) -> Self {
+        self.try_softplus().unwrap()
+    }
+    /// See [softplus]
+    pub fn try_softplus(self) -> Result<Self, D::Err> {
+        try_unary_op(SoftplusKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_softplus() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.leaky_trace().softplus();
+        assert_close(
+            &r.array(),
+            &[
+                0.12692805,
+                0.31326166,
+                TestDtype::ln(2.0),
+                1.3132616,
+                2.126928,
+            ],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.023840584, 0.053788286, 0.1, 0.14621171, 0.17615943],
+        );
+    }
+
+    #[test]
+    fn test_softplus_large_inputs() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1000.0, 1000.0]);
+        let r = x.leaky_trace().softplus();
+        assert_eq!(r.array(), [0.0, 1000.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_softplus_finite_differences() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal::<Rank1<8>>() * 3.0;
+        let err = check_gradient(|x| x.softplus().sum(), &x, 1e-3);
+        assert!(err < 1e-2, "{err}");
+    }
+}
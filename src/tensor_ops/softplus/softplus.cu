@@ -0,0 +1,24 @@
+#include "unary_op_macros.cuh"
+#include "cuda_utils.cuh"
+
+struct SoftplusKernelOp {};
+
+template<typename T>
+__device__ T softplus_fwd(T x) {
+    return maxg(x, (T)0.0) + log1pg(expg(-absg(x)));
+}
+
+template<typename T>
+__device__ T softplus_bwd(T x) {
+    return 1.0 / (1.0 + expg(-x));
+}
+
+UNARY_OP(float, softplus_fwd_f32, softplus_bwd_f32, SoftplusKernelOp,
+    softplus_fwd(x),
+    softplus_bwd(x)
+)
+
+UNARY_OP(double, softplus_fwd_f64, softplus_bwd_f64, SoftplusKernelOp,
+    softplus_fwd(x),
+    softplus_bwd(x)
+)
@@ -3,7 +3,11 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use crate::{shapes::*, tensor::*};
+use crate::{
+    shapes::*,
+    tensor::*,
+    tensor_ops::{reshape_to::ReshapeKernel, BroadcastTo, ReshapeTo},
+};
 
 pub trait SumKernel<E: Dtype>: DeviceStorage {
     fn forward<Src: Shape, Dst: Shape, Ax: Axes>(
@@ -77,6 +81,94 @@ impl<S: Shape, E: Dtype, D: SumKernel<E>, T: Tape<E, D>> SumTo for Tensor<S, E,
     }
 }
 
+impl<S: Shape, E: Dtype, D: SumKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// Sum reduction that keeps the reduced axes with length 1.
+    /// **Pytorch equivalent**: `t.sum(Ax, keepdim=True)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r: Tensor<Rank2<2, 1>, f32, _> = t.sum_keepdim::<Axis<1>>();
+    /// assert_eq!(r.array(), [[6.0], [-6.0]]);
+    /// ```
+    pub fn sum_keepdim<Ax: Axes>(self) -> Tensor<KeepDim<S, Ax>, E, D, T>
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_sum_keepdim::<Ax>().unwrap()
+    }
+
+    /// Fallible version of [Tensor::sum_keepdim]
+    #[allow(clippy::type_complexity)]
+    pub fn try_sum_keepdim<Ax: Axes>(self) -> Result<Tensor<KeepDim<S, Ax>, E, D, T>, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        let dst = <S as ReduceKeepDimTo<Ax>>::keep_dim(self.shape());
+        self.try_sum::<<S as ReduceShape<Ax>>::Reduced, Ax>()?
+            .try_broadcast_like::<KeepDim<S, Ax>, Ax>(&dst)
+    }
+}
+
+/// Sums `t` down to `dst` along axes `Ax`, undoing a broadcast. This is what the gradient
+/// of a broadcasted tensor needs to go through to get back to the original shape.
+///
+/// `dst` can either have the axes `Ax` removed (e.g. leading axes that were added by the
+/// broadcast), or kept with size 1.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let g: Tensor<Rank2<2, 3>, f32, _> = dev.ones();
+/// let r: Tensor<Rank1<3>, f32, _> = g.clone().reduce_to::<_, Axis<0>>(&Default::default());
+/// assert_eq!(r.array(), [2.0; 3]);
+/// let r: Tensor<Rank2<1, 3>, f32, _> = g.reduce_to::<_, Axis<0>>(&Default::default());
+/// assert_eq!(r.array(), [[2.0; 3]]);
+/// ```
+pub fn reduce_to<Dst: Shape, Ax: Axes, S: Shape, E: Dtype, D, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    dst: &Dst,
+) -> Tensor<Dst, E, D, T>
+where
+    S: ReduceShape<Ax>,
+    D: SumKernel<E> + ReshapeKernel<E>,
+{
+    t.reduce_to::<Dst, Ax>(dst)
+}
+
+impl<S: Shape, E: Dtype, D: SumKernel<E> + ReshapeKernel<E>, T: Tape<E, D>> Tensor<S, E, D, T> {
+    /// See [reduce_to()]
+    pub fn reduce_to<Dst: Shape, Ax: Axes>(self, dst: &Dst) -> Tensor<Dst, E, D, T>
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_reduce_to::<Dst, Ax>(dst).unwrap()
+    }
+
+    /// See [reduce_to()]
+    pub fn try_reduce_to<Dst: Shape, Ax: Axes>(
+        self,
+        dst: &Dst,
+    ) -> Result<Tensor<Dst, E, D, T>, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        let reduced: <S as ReduceShape<Ax>>::Reduced = self.shape.reduced();
+        let keep_dim = <S as ReduceKeepDimTo<Ax>>::keep_dim(&self.shape);
+        assert!(
+            dst.concrete().into_iter().eq(reduced.concrete())
+                || dst.concrete().into_iter().eq(keep_dim.concrete()),
+            "Can't reduce {:?} to {:?}",
+            self.shape.concrete(),
+            dst.concrete()
+        );
+        self.try_sum::<<S as ReduceShape<Ax>>::Reduced, Ax>()?
+            .try_reshape_like(dst)
+            .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,6 +236,39 @@ mod tests {
         assert_close(&g.get(&t1).array(), &[[5.0; 3]; 4]);
     }
 
+    #[test]
+    fn test_reduce_to() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+        let a = t.array();
+        let col_sums = [0, 1, 2, 3].map(|j| a[0][j] + a[1][j] + a[2][j]);
+
+        let r = t
+            .leaky_trace()
+            .reduce_to::<Rank1<4>, Axis<0>>(&Default::default());
+        assert_close(&r.array(), &col_sums);
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &[col_sums.map(TestDtype::exp); 3]);
+
+        let r = t
+            .leaky_trace()
+            .reduce_to::<Rank2<1, 4>, Axis<0>>(&Default::default());
+        assert_close(&r.array(), &[col_sums]);
+
+        let r = t.leaky_trace().reduce_to::<Rank0, Axes2<0, 1>>(&());
+        assert_close(&r.array(), &col_sums.iter().sum());
+        let g = r.backward();
+        assert_eq!(g.get(&t).array(), [[1.0; 4]; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reduce_to_wrong_shape() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 4>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank2<4, 1>, TestDtype, _> = t.reduce_to::<_, Axis<0>>(&Default::default());
+    }
+
     #[test]
     fn test_sum_chunking() {
         let dev: TestDevice = Default::default();
@@ -173,4 +298,24 @@ mod tests {
         let g = c.backward();
         assert_eq!(g.get(&a).array(), [8.0; 3]);
     }
+
+    #[test]
+    fn test_sum_keepdim() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank2<3, 1>, TestDtype, _, _> = t.leaky_trace().sum_keepdim::<Axis<1>>();
+        let r2 = t.leaky_trace().sum::<Rank1<3>, _>();
+        assert_close(&r.array().map(|x| x[0]), &r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+
+        let t: Tensor<(usize, Const<4>), TestDtype, _> = dev.zeros_like(&(5, Const));
+        let r: Tensor<(usize, Const<1>), TestDtype, _> = t.sum_keepdim::<Axis<1>>();
+        assert_eq!(r.shape().concrete(), [5, 1]);
+
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.ones();
+        let r: Tensor<Rank3<1, 3, 1>, TestDtype, _> = t.sum_keepdim::<Axes2<0, 2>>();
+        assert_eq!(r.array(), [[[8.0], [8.0], [8.0]]]);
+    }
 }
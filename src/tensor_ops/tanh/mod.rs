@@ -40,7 +40,7 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<TanhKernelOp, E>, T: Tape<E, D>> Tensor<
 
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tensor_ops::*, tests::*};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     #[test]
     fn test_tanh() {
@@ -57,4 +57,22 @@ mod tests {
             &[0.014130163, 0.083994865, 0.2, 0.083994865, 0.014130163],
         );
     }
+
+    #[test]
+    fn test_tanh_saturates() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1000.0, -50.0, 50.0, 1000.0]);
+        let r = x.leaky_trace().tanh();
+        assert_eq!(r.array(), [-1.0, -1.0, 1.0, 1.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0; 4]);
+    }
+
+    #[test]
+    fn test_tanh_finite_differences() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal::<Rank1<8>>() * 2.0;
+        let err = check_gradient(|x| x.tanh().sum(), &x, 1e-3);
+        assert!(err < 1e-2, "{err}");
+    }
 }
@@ -0,0 +1,70 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{cpu::NdIndex, Cpu, Tensor},
+};
+
+use std::{sync::Arc, vec::Vec};
+
+fn strided_index<S: Shape>(idx: S::Concrete, strides: S::Concrete) -> usize {
+    idx.into_iter().zip(strides).map(|(i, s)| i * s).sum()
+}
+
+fn is_nan<E: PartialOrd>(x: E) -> bool {
+    x.partial_cmp(&x).is_none()
+}
+
+impl<E: Dtype> super::TopKKernel<E> for Cpu {
+    fn forward<S: Shape, Dst: Shape<Concrete = S::Concrete>>(
+        &self,
+        axis: usize,
+        inp: &Tensor<S, E, Self>,
+        values: &mut Tensor<Dst, E, Self>,
+        indices: &mut Tensor<Dst, usize, Self>,
+    ) -> Result<(), Self::Err> {
+        let size = inp.shape.concrete()[axis];
+        let k = values.shape.concrete()[axis];
+        let (v_strides, i_strides) = (values.strides, indices.strides);
+        let v_buf = Arc::make_mut(&mut values.data);
+        let i_buf = Arc::make_mut(&mut indices.data);
+
+        let mut lane: Vec<(E, usize)> = Vec::with_capacity(size);
+        let mut out_idx = NdIndex::new(values.shape, v_strides);
+        while let Some((_, mut idx)) = out_idx.next_with_idx() {
+            if idx[axis] != 0 {
+                continue;
+            }
+            lane.clear();
+            for j in 0..size {
+                idx[axis] = j;
+                lane.push((inp.data[strided_index::<S>(idx, inp.strides)], j));
+            }
+            // stable sort, so ties keep the lower index first. NaNs go last.
+            lane.sort_by(|a, b| match b.0.partial_cmp(&a.0) {
+                Some(ord) => ord,
+                None => is_nan(a.0).cmp(&is_nan(b.0)),
+            });
+            for (r, &(v, j)) in lane.iter().take(k).enumerate() {
+                idx[axis] = r;
+                v_buf[strided_index::<S>(idx, v_strides)] = v;
+                i_buf[strided_index::<S>(idx, i_strides)] = j;
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<S: Shape, Dst: Shape<Concrete = S::Concrete>>(
+        &self,
+        axis: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        indices: &Tensor<Dst, usize, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err> {
+        let mut out_idx = NdIndex::new(indices.shape, indices.strides);
+        while let Some((o, mut idx)) = out_idx.next_with_idx() {
+            idx[axis] = indices.data[o];
+            grad_inp[strided_index::<S>(idx, inp.strides)] += grad_out[o];
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+#![allow(clippy::type_complexity)]
+
+use crate::{shapes::*, tensor::*};
+
+mod cpu_kernel;
+
+pub trait TopKKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape, Dst: Shape<Concrete = S::Concrete>>(
+        &self,
+        axis: usize,
+        inp: &Tensor<S, E, Self>,
+        values: &mut Tensor<Dst, E, Self>,
+        indices: &mut Tensor<Dst, usize, Self>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<S: Shape, Dst: Shape<Concrete = S::Concrete>>(
+        &self,
+        axis: usize,
+        inp: &Tensor<S, E, Self>,
+        grad_inp: &mut Self::Vec<E>,
+        indices: &Tensor<Dst, usize, Self>,
+        grad_out: &Self::Vec<E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Finds the `k` largest values along axis `Ax`, sorted in descending order,
+/// and their indices along `Ax`. Ties are broken by the lower index.
+/// The size of `Ax` in the outputs becomes a `usize`.
+///
+/// Only the values are differentiable. Their gradient is routed to the
+/// positions they were selected from.
+///
+/// **Pytorch equivalent**: `t.topk(k, dim)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 5.0, 3.0], [4.0, 2.0, 4.0]]);
+/// let (values, indices) = t.topk::<Axis<1>>(2);
+/// assert_eq!(values.as_vec(), [5.0, 3.0, 4.0, 4.0]);
+/// assert_eq!(indices.as_vec(), [1, 2, 0, 2]);
+/// ```
+pub fn topk<Ax: Axes<Array = [isize; 1]>, S, E: Dtype, D, T: Tape<E, D>>(
+    t: Tensor<S, E, D, T>,
+    k: usize,
+) -> (Tensor<S::Resized, E, D, T>, Tensor<S::Resized, usize, D>)
+where
+    S: ResizeDimShape<Ax>,
+    D: TopKKernel<E> + ZerosTensor<E> + ZerosTensor<usize>,
+{
+    t.topk::<Ax>(k)
+}
+
+impl<S: Shape, E: Dtype, D, T: Tape<E, D>> Tensor<S, E, D, T>
+where
+    D: TopKKernel<E> + ZerosTensor<E> + ZerosTensor<usize>,
+{
+    /// See [topk()]
+    pub fn topk<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        k: usize,
+    ) -> (Tensor<S::Resized, E, D, T>, Tensor<S::Resized, usize, D>)
+    where
+        S: ResizeDimShape<Ax>,
+    {
+        self.try_topk::<Ax>(k).unwrap()
+    }
+
+    /// See [topk()]
+    pub fn try_topk<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        k: usize,
+    ) -> Result<(Tensor<S::Resized, E, D, T>, Tensor<S::Resized, usize, D>), D::Err>
+    where
+        S: ResizeDimShape<Ax>,
+    {
+        let axis = Ax::as_array()[0] as usize;
+        let size = self.shape.concrete()[axis];
+        assert!(
+            k <= size,
+            "k ({k}) must be at most the size of the axis ({size})"
+        );
+        let dst = self.shape.resize_dim(k);
+        let (inp, mut tape) = self.split_tape();
+        let mut values = inp.device.try_zeros_like(&dst)?;
+        let mut indices = inp.device.try_zeros_like(&dst)?;
+        inp.device.forward(axis, &inp, &mut values, &mut indices)?;
+        let inp_ghost = inp.ghost();
+        let out_ghost = values.ghost();
+        let idx = indices.clone();
+        tape.add_backward_op(move |grads| {
+            grads.try_alloc_for(&inp_ghost)?;
+            grads.try_alloc_for(&out_ghost)?;
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp_ghost, &out_ghost);
+            inp.device.backward(axis, &inp, grad_inp, &idx, grad_out)
+        });
+        Ok((values.put_tape(tape), indices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_topk_last_axis() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 5>, TestDtype, _> =
+            dev.tensor([[0.5, -1.0, 2.0, 0.5, 3.0], [1.0, 1.0, 0.0, 1.0, -2.0]]);
+
+        let (values, indices) = t.leaky_trace().topk::<Axis<1>>(3);
+        assert_eq!(values.shape(), &(Const::<2>, 3));
+        assert_eq!(values.as_vec(), [3.0, 2.0, 0.5, 1.0, 1.0, 1.0]);
+        assert_eq!(indices.as_vec(), [4, 2, 0, 0, 1, 3]);
+
+        let g = values.exp().sum().backward();
+        let e = t.clone().exp().array();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [e[0][0], 0.0, e[0][2], 0.0, e[0][4]],
+                [e[1][0], e[1][1], 0.0, e[1][3], 0.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_topk_first_axis() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<3, 2>, TestDtype, _> = dev.tensor([[1.0, 6.0], [3.0, 4.0], [2.0, 5.0]]);
+
+        let (values, indices) = t.leaky_trace().topk::<Axis<0>>(1);
+        assert_eq!(values.as_vec(), [3.0, 6.0]);
+        assert_eq!(indices.as_vec(), [1, 0]);
+
+        let g = values.sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 1.0], [1.0, 0.0], [0.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_topk_nan_last() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<4>, TestDtype, _> = dev.tensor([1.0, TestDtype::NAN, 3.0, 2.0]);
+
+        let (values, indices) = t.clone().topk::<Axis<0>>(2);
+        assert_eq!(values.as_vec(), [3.0, 2.0]);
+        assert_eq!(indices.as_vec(), [2, 3]);
+
+        let (values, indices) = t.topk::<Axis<0>>(4);
+        let values = values.as_vec();
+        assert_eq!(values[..3], [3.0, 2.0, 1.0]);
+        assert!(values[3].is_nan());
+        assert_eq!(indices.as_vec(), [2, 3, 0, 1]);
+    }
+}
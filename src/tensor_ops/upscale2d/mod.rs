@@ -357,6 +357,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_upscale2d_bilinear_finite_differences() {
+        let dev = TestDevice::default();
+
+        let x: Tensor<Rank3<1, 2, 2>, TestDtype, _> = dev.tensor([[[1.0, 0.0], [2.0, 3.0]]]);
+        let err = check_gradient(|x| x.upscale2d::<4, 4, _>(Bilinear).exp().mean(), &x, 1e-3);
+        assert!(err < 1e-3, "{err}");
+    }
+
     #[test]
     fn test_upscale2d_bilinear_uneven() {
         let dev = TestDevice::default();
@@ -0,0 +1,73 @@
+use super::{Backward, Device};
+use crate::{shapes::*, tensor::*};
+
+use num_traits::Float;
+
+/// Compares the gradient of `f` computed by backprop against central finite
+/// differences `(f(x + eps) - f(x - eps)) / (2 * eps)`, one element of `input`
+/// at a time. Returns the largest error between the two, where the error for each
+/// element is `|analytic - numeric| / max(1, |analytic|, |numeric|)`.
+///
+/// Useful for checking the backward pass of custom ops. Note that `f` is evaluated
+/// twice per element of `input`, so keep the input small. Lower precision dtypes
+/// need a larger `eps`.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x: Tensor<Rank1<4>, f64, _> = dev.sample_normal();
+/// let err = check_gradient(|x| x.exp().sum(), &x, 1e-6);
+/// assert!(err < 1e-6);
+/// ```
+pub fn check_gradient<S: Shape, E: Dtype + Float, D: Device<E>, F>(
+    f: F,
+    input: &Tensor<S, E, D>,
+    eps: E,
+) -> E
+where
+    F: Fn(Tensor<S, E, D, OwnedTape<E, D>>) -> Tensor<Rank0, E, D, OwnedTape<E, D>>,
+{
+    let analytic = f(input.leaky_trace()).backward().get(input).as_vec();
+    let eval = |data: std::vec::Vec<E>| -> E {
+        let t = input.device.tensor_from_vec(data, input.shape);
+        f(t.leaky_traced()).as_vec()[0]
+    };
+
+    let two = E::one() + E::one();
+    let data = input.as_vec();
+    let mut max_err = E::zero();
+    for (i, &a) in analytic.iter().enumerate() {
+        let mut plus = data.clone();
+        plus[i] += eps;
+        let mut minus = data.clone();
+        minus[i] -= eps;
+        let n = (eval(plus) - eval(minus)) / (two * eps);
+        let err = (a - n).abs() / E::one().max(a.abs()).max(n.abs());
+        max_err = max_err.max(err);
+    }
+    max_err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_check_gradient_exp() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let err = check_gradient(|x| x.exp().sum(), &x, 1e-3);
+        assert!(err < 1e-3, "{err}");
+    }
+
+    #[test]
+    fn test_check_gradient_wrong_backward() {
+        let dev: Cpu = Default::default();
+        let x: Tensor<Rank1<5>, TestDtype, _> = dev.sample_normal();
+        let err = check_gradient(|x| x.map_with_grad(|x| x * x, |x| x).sum(), &x, 1e-3);
+        assert!(err > 0.1, "{err}");
+        let err = check_gradient(|x| x.map_with_grad(|x| x * x, |x| 2.0 * x).sum(), &x, 1e-3);
+        assert!(err < 1e-3, "{err}");
+    }
+}
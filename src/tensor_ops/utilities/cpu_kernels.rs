@@ -1,14 +1,31 @@
 use std::borrow::Cow;
 
+#[cfg(not(feature = "rayon"))]
+use crate::tensor::cpu::LendingIterator;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
 use super::ops::{BinaryKernel, UnaryKernel};
 use crate::{
     shapes::{Dtype, Shape},
     tensor::{
-        cpu::{Cpu, LendingIterator, NdIndex},
+        cpu::{Cpu, CpuError, NdIndex},
         unique_id, GhostTensor, Tensor, ZerosTensor,
     },
 };
 
+/// Requires [Sync] only when ops are run in parallel with rayon.
+#[cfg(feature = "rayon")]
+pub trait MaybeSync: Sync {}
+#[cfg(feature = "rayon")]
+impl<T: Sync> MaybeSync for T {}
+
+/// Requires [Sync] only when ops are run in parallel with rayon.
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeSync {}
+#[cfg(not(feature = "rayon"))]
+impl<T> MaybeSync for T {}
+
 pub trait UnaryDerivative<E> {
     /// Whether the [UnaryDerivative::df] function can re-use the output
     /// from [UnaryDerivative::f].
@@ -43,7 +60,7 @@ pub trait BinaryDerivative<E>: std::fmt::Debug {
     }
 }
 
-impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
+impl<E: Dtype, Op: UnaryDerivative<E> + MaybeSync> UnaryKernel<Op, E> for Cpu {
     const BACKWARD_WITHOUT_INP: bool = Op::DF_USES_FX;
     const BACKWARD_WITHOUT_DATA: bool = Op::HAS_CONST_DF;
 
@@ -52,6 +69,8 @@ impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
         op: Op,
         inp: Cow<Tensor<S, E, Self>>,
     ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        #[cfg(feature = "timings")]
+        let _timer = self.time_kernel::<Op>();
         let mut out = match inp {
             Cow::Borrowed(inp) => {
                 // allocate a new data buffer
@@ -72,9 +91,12 @@ impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
         };
         // NOTE: we can iterate over buf here because we know inp & out
         // have exact same strides due to clone.
+        #[cfg(not(feature = "rayon"))]
         for x in out.buf_iter_mut() {
             *x = op.f(x);
         }
+        #[cfg(feature = "rayon")]
+        out.buf_par_iter_mut().for_each(|x| *x = op.f(x));
         Ok(out)
     }
     fn backward<S: Shape>(
@@ -85,22 +107,20 @@ impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
         out: Result<&Tensor<S, E, Self>, &GhostTensor<S, E, Self>>,
         grad_out: &Self::Vec<E>,
     ) -> Result<(), Self::Err> {
+        #[cfg(not(feature = "rayon"))]
+        let grad_inp = grad_inp.iter_mut().enumerate();
+        #[cfg(feature = "rayon")]
+        let grad_inp = grad_inp.par_iter_mut().enumerate();
         match (inp, out) {
             (Err(_), Err(_)) => {
                 let df = op.const_df();
-                for (i, x) in grad_inp.iter_mut().enumerate() {
-                    *x += df * grad_out[i];
-                }
+                grad_inp.for_each(|(i, x)| *x += df * grad_out[i]);
             }
             (Err(_), Ok(out)) => {
-                for (i, x) in grad_inp.iter_mut().enumerate() {
-                    *x += op.df(&out.data[i]) * grad_out[i];
-                }
+                grad_inp.for_each(|(i, x)| *x += op.df(&out.data[i]) * grad_out[i]);
             }
             (Ok(inp), Err(_)) => {
-                for (i, x) in grad_inp.iter_mut().enumerate() {
-                    *x += op.df(&inp.data[i]) * grad_out[i];
-                }
+                grad_inp.for_each(|(i, x)| *x += op.df(&inp.data[i]) * grad_out[i]);
             }
             _ => unreachable!(),
         }
@@ -108,7 +128,37 @@ impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
     }
 }
 
-impl<E: Dtype, Op: BinaryDerivative<E>> BinaryKernel<Op, E> for Cpu {
+fn binary_forward_borrowed<S: Shape, E: Dtype, Op: BinaryDerivative<E> + MaybeSync>(
+    dev: &Cpu,
+    op: Op,
+    lhs: &Tensor<S, E, Cpu>,
+    rhs: &Tensor<S, E, Cpu>,
+) -> Result<Tensor<S, E, Cpu>, CpuError> {
+    let mut out = dev.try_zeros_like(&lhs.shape)?;
+    #[cfg(not(feature = "rayon"))]
+    {
+        let mut lhs_iter = lhs.iter();
+        let mut rhs_iter = rhs.iter();
+        for o in out.buf_iter_mut() {
+            let l = lhs_iter.next().unwrap();
+            let r = rhs_iter.next().unwrap();
+            *o = op.f(l, r);
+        }
+    }
+    #[cfg(feature = "rayon")]
+    {
+        let lhs_idx = NdIndex::new(lhs.shape, lhs.strides);
+        let rhs_idx = NdIndex::new(rhs.shape, rhs.strides);
+        out.buf_par_iter_mut().enumerate().for_each(|(i, o)| {
+            let l = &lhs.data[lhs_idx.get_strided_index(i)];
+            let r = &rhs.data[rhs_idx.get_strided_index(i)];
+            *o = op.f(l, r);
+        });
+    }
+    Ok(out)
+}
+
+impl<E: Dtype, Op: BinaryDerivative<E> + MaybeSync> BinaryKernel<Op, E> for Cpu {
     const BACKWARD_WITHOUT_DATA: bool = Op::HAS_CONST_DF;
     fn forward<S: Shape>(
         &self,
@@ -116,18 +166,10 @@ impl<E: Dtype, Op: BinaryDerivative<E>> BinaryKernel<Op, E> for Cpu {
         lhs: Cow<Tensor<S, E, Self>>,
         rhs: Cow<Tensor<S, E, Self>>,
     ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        #[cfg(feature = "timings")]
+        let _timer = self.time_kernel::<Op>();
         match (lhs, rhs) {
-            (Cow::Borrowed(lhs), Cow::Borrowed(rhs)) => {
-                let mut out = self.try_zeros_like(&lhs.shape)?;
-                let mut lhs_iter = lhs.iter();
-                let mut rhs_iter = rhs.iter();
-                for o in out.buf_iter_mut() {
-                    let l = lhs_iter.next().unwrap();
-                    let r = rhs_iter.next().unwrap();
-                    *o = op.f(l, r);
-                }
-                Ok(out)
-            }
+            (Cow::Borrowed(lhs), Cow::Borrowed(rhs)) => binary_forward_borrowed(self, op, lhs, rhs),
             (Cow::Owned(mut lhs), Cow::Owned(mut rhs)) => {
                 let lhs_valid = lhs.strides == lhs.shape.strides();
                 let rhs_valid = rhs.strides == rhs.shape.strides();
@@ -136,26 +178,41 @@ impl<E: Dtype, Op: BinaryDerivative<E>> BinaryKernel<Op, E> for Cpu {
                     let rhs_count = std::sync::Arc::strong_count(&rhs.data);
                     if rhs_valid && (rhs_count == 1 || !lhs_valid || lhs_count != 1) {
                         rhs.id = unique_id();
-                        let mut lhs_idx = NdIndex::new(lhs.shape, lhs.strides);
-                        for r in rhs.buf_iter_mut() {
-                            *r = op.f(&lhs.data[lhs_idx.next().unwrap()], r);
+                        #[cfg(not(feature = "rayon"))]
+                        {
+                            let mut lhs_idx = NdIndex::new(lhs.shape, lhs.strides);
+                            for r in rhs.buf_iter_mut() {
+                                *r = op.f(&lhs.data[lhs_idx.next().unwrap()], r);
+                            }
+                        }
+                        #[cfg(feature = "rayon")]
+                        {
+                            let lhs_idx = NdIndex::new(lhs.shape, lhs.strides);
+                            rhs.buf_par_iter_mut().enumerate().for_each(|(i, r)| {
+                                *r = op.f(&lhs.data[lhs_idx.get_strided_index(i)], r);
+                            });
                         }
                         Ok(rhs)
                     } else {
                         lhs.id = unique_id();
-                        let mut rhs_idx = NdIndex::new(rhs.shape, rhs.strides);
-                        for l in lhs.buf_iter_mut() {
-                            *l = op.f(l, &rhs.data[rhs_idx.next().unwrap()]);
+                        #[cfg(not(feature = "rayon"))]
+                        {
+                            let mut rhs_idx = NdIndex::new(rhs.shape, rhs.strides);
+                            for l in lhs.buf_iter_mut() {
+                                *l = op.f(l, &rhs.data[rhs_idx.next().unwrap()]);
+                            }
+                        }
+                        #[cfg(feature = "rayon")]
+                        {
+                            let rhs_idx = NdIndex::new(rhs.shape, rhs.strides);
+                            lhs.buf_par_iter_mut().enumerate().for_each(|(i, l)| {
+                                *l = op.f(l, &rhs.data[rhs_idx.get_strided_index(i)]);
+                            });
                         }
                         Ok(lhs)
                     }
                 } else {
-                    <Self as BinaryKernel<Op, E>>::forward(
-                        self,
-                        op,
-                        Cow::Borrowed(&lhs),
-                        Cow::Borrowed(&rhs),
-                    )
+                    binary_forward_borrowed(self, op, &lhs, &rhs)
                 }
             }
             _ => unreachable!(),
@@ -205,3 +262,70 @@ impl<E: Dtype, Op: BinaryDerivative<E>> BinaryKernel<Op, E> for Cpu {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*};
+    use std::{collections::HashSet, sync::Mutex, thread::ThreadId};
+
+    #[derive(Default)]
+    struct RecordThreads(Mutex<HashSet<ThreadId>>);
+
+    impl UnaryDerivative<f32> for &RecordThreads {
+        const DF_USES_FX: bool = false;
+        const HAS_CONST_DF: bool = false;
+        fn f(&self, x: &f32) -> f32 {
+            self.0.lock().unwrap().insert(std::thread::current().id());
+            x + 1.0
+        }
+        fn df(&self, _: &f32) -> f32 {
+            1.0
+        }
+    }
+
+    #[test]
+    fn test_rayon_matches_serial() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<Rank2<64, 129>, f32, _> = dev.sample_normal();
+        let b: Tensor<Rank1<129>, f32, _> = dev.sample_normal();
+
+        let a_vec = a.as_vec();
+        let b_vec = b.as_vec();
+
+        let r = a.clone().exp().as_vec();
+        for (r, a) in r.iter().zip(a_vec.iter()) {
+            assert_eq!(*r, a.exp());
+        }
+
+        let r = (a.clone() * b.clone().broadcast()).as_vec();
+        for (i, (r, a)) in r.iter().zip(a_vec.iter()).enumerate() {
+            assert_eq!(*r, a * b_vec[i % 129]);
+        }
+
+        let r = (b.clone().broadcast::<Rank2<64, 129>, _>() - a.clone()).as_vec();
+        for (i, (r, a)) in r.iter().zip(a_vec.iter()).enumerate() {
+            assert_eq!(*r, b_vec[i % 129] - a);
+        }
+
+        let r = (a.clone().permute::<_, Axes2<1, 0>>() + a.clone().permute()).as_vec();
+        for (i, r) in r.iter().enumerate() {
+            let (col, row) = (i / 64, i % 64);
+            assert_eq!(*r, 2.0 * a_vec[row * 129 + col]);
+        }
+    }
+
+    #[test]
+    fn test_rayon_uses_multiple_threads() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<100000>, f32, _> = dev.zeros();
+        let op = RecordThreads::default();
+        let out = pool.install(|| UnaryKernel::forward(&dev, &op, Cow::Borrowed(&t)).unwrap());
+        assert_eq!(out.as_vec(), std::vec![1.0; 100000]);
+        assert!(op.0.lock().unwrap().len() > 1);
+    }
+}
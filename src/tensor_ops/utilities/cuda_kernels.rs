@@ -74,11 +74,15 @@ impl<E: Dtype, K: UnaryOpCudaKernel<E> + DeviceRepr> UnaryKernel<K, E> for Cuda
         }
 
         let fwd_fn = self.dev.get_func(K::MODULE_NAME, K::FWD_FN_NAME).unwrap();
+        #[cfg(feature = "timings")]
+        let _timer = self.time_kernel::<K>()?;
 
         match inp {
             Cow::Borrowed(inp) => {
                 let numel = inp.data.len();
-                let mut storage = unsafe { self.dev.alloc::<E>(numel) }?;
+                let mut storage = unsafe { self.dev.alloc::<E>(numel) }.map_err(|e| {
+                    CudaError::from_alloc(K::FWD_FN_NAME, e, numel * std::mem::size_of::<E>())
+                })?;
 
                 let cfg = launch_cfg::<128>(numel as u32);
                 let params = (op, numel, inp.data.as_ref(), &mut storage);
@@ -233,6 +237,8 @@ impl<E: Dtype, K: BinaryOpCudaKernel<E> + DeviceRepr + Clone> BinaryKernel<K, E>
                 .load_ptx(K::PTX_SRC.into(), K::MODULE_NAME, &K::ALL_FN_NAMES)?;
         }
         let fwd_fn = self.dev.get_func(K::MODULE_NAME, K::FWD_FN_NAME).unwrap();
+        #[cfg(feature = "timings")]
+        let _timer = self.time_kernel::<K>()?;
 
         let shape = match &lhs {
             Cow::Borrowed(lhs) => lhs.shape,
@@ -259,7 +265,9 @@ impl<E: Dtype, K: BinaryOpCudaKernel<E> + DeviceRepr + Clone> BinaryKernel<K, E>
 
         match (lhs, rhs) {
             (Cow::Borrowed(lhs), Cow::Borrowed(rhs)) => {
-                let mut storage = unsafe { self.dev.alloc::<E>(numel) }?;
+                let mut storage = unsafe { self.dev.alloc::<E>(numel) }.map_err(|e| {
+                    CudaError::from_alloc(K::FWD_FN_NAME, e, numel * std::mem::size_of::<E>())
+                })?;
                 let params = (
                     op,
                     numel,             // const size_t numel,
@@ -313,7 +321,9 @@ impl<E: Dtype, K: BinaryOpCudaKernel<E> + DeviceRepr + Clone> BinaryKernel<K, E>
                         Ok(lhs)
                     }
                 } else {
-                    let mut storage = unsafe { self.dev.alloc::<E>(numel) }?;
+                    let mut storage = unsafe { self.dev.alloc::<E>(numel) }.map_err(|e| {
+                        CudaError::from_alloc(K::FWD_FN_NAME, e, numel * std::mem::size_of::<E>())
+                    })?;
                     let params = (
                         op,
                         numel,             // const size_t numel,
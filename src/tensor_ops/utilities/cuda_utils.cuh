@@ -121,6 +121,8 @@ __device__ __forceinline__ float ming(float a, float b) { return fminf(a, b); }
 __device__ __forceinline__ double ming(double a, double b) { return fmin(a, b); }
 __device__ __forceinline__ float logg(float a) { return logf(a); }
 __device__ __forceinline__ double logg(double a) { return log(a); }
+__device__ __forceinline__ float log1pg(float a) { return log1pf(a); }
+__device__ __forceinline__ double log1pg(double a) { return log1p(a); }
 __device__ __forceinline__ float expg(float a) { return expf(a); }
 __device__ __forceinline__ double expg(double a) { return exp(a); }
 __device__ __forceinline__ float absg(float a) { return fabsf(a); }
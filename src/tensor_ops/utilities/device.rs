@@ -74,13 +74,20 @@ pub trait Device<E: Dtype>:
     + UnaryKernel<super::super::clamp::ClampKernelOp<E>, E>
     + UnaryKernel<super::super::cos::CosKernelOp, E>
     + super::super::dropout::DropoutKernel<E>
+    + UnaryKernel<super::super::elu::EluKernelOp<E>, E>
+    + UnaryKernel<super::super::elu::SeluKernelOp, E>
     + UnaryKernel<super::super::exp::ExpKernelOp, E>
     + UnaryKernel<super::super::ln::LnKernelOp, E>
     + UnaryKernel<super::super::nans_to::NansToKernelOp<E>, E>
     + UnaryKernel<super::super::negate::NegateKernelOp, E>
     + UnaryKernel<super::super::relu::ReLUKernelOp, E>
+    + UnaryKernel<super::super::leaky_relu::LeakyReLUKernelOp<E>, E>
     + UnaryKernel<super::super::gelu::GeLUKernelOp, E>
+    + UnaryKernel<super::super::hard_sigmoid::HardSigmoidKernelOp, E>
+    + UnaryKernel<super::super::hard_sigmoid::HardSwishKernelOp, E>
     + UnaryKernel<super::super::sigmoid::SigmoidKernelOp, E>
+    + UnaryKernel<super::super::softplus::SoftplusKernelOp, E>
+    + UnaryKernel<super::super::mish::MishKernelOp, E>
     + UnaryKernel<super::super::sin::SinKernelOp, E>
     + UnaryKernel<super::super::sqrt::SqrtKernelOp, E>
     + UnaryKernel<super::super::square::SquareKernelOp, E>
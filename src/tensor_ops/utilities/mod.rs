@@ -1,4 +1,5 @@
 mod backward;
+mod check_gradient;
 pub(crate) mod cpu_kernels;
 #[cfg(feature = "cuda")]
 pub(crate) mod cuda_kernels;
@@ -7,4 +8,5 @@ pub(crate) mod ops;
 pub(crate) mod reduction_utils;
 
 pub use backward::Backward;
+pub use check_gradient::check_gradient;
 pub use device::Device;